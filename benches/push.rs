@@ -0,0 +1,67 @@
+use abao::AbaoVec;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::mem::MaybeUninit;
+use std::sync::atomic::AtomicUsize;
+use std::thread;
+
+const CAP: usize = 1 << 16;
+
+fn uninit_buf() -> Vec<MaybeUninit<usize>> {
+    (0..CAP).map(|_| MaybeUninit::uninit()).collect()
+}
+
+fn fill(v: &AbaoVec<'_, usize>, threads: usize) {
+    thread::scope(|s| {
+        for t in 0..threads {
+            s.spawn(move || {
+                for i in (t..CAP).step_by(threads) {
+                    v.push(i).unwrap();
+                }
+            });
+        }
+    });
+}
+
+fn concurrent_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concurrent_push");
+    for threads in [1, 4, 16] {
+        group.bench_with_input(BenchmarkId::new("spin", threads), &threads, |b, &t| {
+            let mut buf = uninit_buf();
+            b.iter(|| fill(&AbaoVec::new(&mut buf[..]), t));
+        });
+        group.bench_with_input(BenchmarkId::new("seq", threads), &threads, |b, &t| {
+            let mut buf = uninit_buf();
+            let mut seq: Vec<AtomicUsize> = (0..CAP).map(|_| AtomicUsize::new(0)).collect();
+            b.iter(|| fill(&AbaoVec::new_seq(&mut buf[..], &mut seq[..]), t));
+        });
+    }
+    group.finish();
+}
+
+fn single_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_push");
+    group.bench_function("push", |b| {
+        let mut buf = uninit_buf();
+        b.iter(|| {
+            let v = AbaoVec::new(&mut buf[..]);
+            for i in 0..CAP {
+                v.push(i).unwrap();
+            }
+        });
+    });
+    group.bench_function("push_unsync", |b| {
+        let mut buf = uninit_buf();
+        b.iter(|| {
+            let v = AbaoVec::new(&mut buf[..]);
+            for i in 0..CAP {
+                // NOTE(unsafe):
+                // this is the only thread pushing
+                unsafe { v.push_unsync(i) }.unwrap();
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, concurrent_push, single_push);
+criterion_main!(benches);
@@ -0,0 +1,9 @@
+#!/usr/bin/env bash
+set -euxo pipefail
+
+TARGET=thumbv7em-none-eabi
+
+rustup target add "$TARGET"
+cargo build --no-default-features --target "$TARGET"
+cargo build --no-default-features --features alloc --target "$TARGET"
+cargo build --no-default-features --features serde --target "$TARGET"
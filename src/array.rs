@@ -0,0 +1,143 @@
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+
+use crate::storage::ArrayStorage;
+use crate::vec::AbaoVecInner;
+
+/// An append only vector with an inline array as buffer.
+///
+/// Behaves exactly like `AbaoVec`, but contains its buffer itself,
+/// so no separate buffer has to be kept alive next to the vector.
+/// The capacity `N` is part of the type.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoArrayVec;
+///
+/// let v = AbaoArrayVec::<u8, 128>::new();
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(v.len(), 3);
+/// assert_eq!(v.get(1), Some(&1));
+/// assert_eq!(v.as_slice(), &[0, 1, 2]);
+/// ```
+pub type AbaoArrayVec<T, const N: usize> = AbaoVecInner<T, ArrayStorage<T, N>>;
+
+impl<T, const N: usize> AbaoArrayVec<T, N> {
+    /// Creates a new empty vector with an inline buffer for `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoArrayVec;
+    /// use abao::OomError;
+    ///
+    /// let v = AbaoArrayVec::<u8, 1>::new();
+    ///
+    /// assert_eq!(v.capacity(), 1);
+    /// assert_eq!(v.push(0), Ok(0));
+    /// assert_eq!(v.push(1), Err((OomError, 1)));
+    /// ```
+    ///
+    /// Being `const`, the vector can be placed in a `static`
+    /// and shared without a separate buffer:
+    ///
+    /// ```
+    /// use abao::AbaoArrayVec;
+    ///
+    /// static EVENTS: AbaoArrayVec<u32, 16> = AbaoArrayVec::new();
+    ///
+    /// EVENTS.push(7).unwrap();
+    /// assert_eq!(EVENTS.as_slice(), &[7]);
+    /// ```
+    #[cfg(not(loom))]
+    pub const fn new() -> Self {
+        Self::from_storage_const(
+            ArrayStorage {
+                slots: [const { Cell::new(MaybeUninit::uninit()) }; N],
+            },
+            N,
+        )
+    }
+
+    /// Creates a new empty vector with an inline buffer for `N` elements.
+    #[cfg(loom)]
+    pub fn new() -> Self {
+        Self::from_storage(ArrayStorage {
+            slots: [const { Cell::new(MaybeUninit::uninit()) }; N],
+        })
+    }
+}
+
+impl<T, const N: usize> Default for AbaoArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::AbaoArrayVec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn drop_elements() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug)]
+        struct X;
+        impl X {
+            fn new() -> X {
+                COUNT.fetch_add(1, Ordering::Relaxed);
+                X
+            }
+        }
+        impl Drop for X {
+            fn drop(&mut self) {
+                COUNT.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+
+        let v = AbaoArrayVec::<X, 4>::new();
+        for _ in 0..3 {
+            v.push(X::new()).unwrap();
+        }
+        assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+        // moving the vector moves its elements along
+        let v = Box::new(v);
+        assert_eq!(v.len(), 3);
+        drop(v);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn in_static() {
+        static V: AbaoArrayVec<usize, 64> = AbaoArrayVec::new();
+        std::thread::scope(|s| {
+            for t in 0..4 {
+                s.spawn(move || {
+                    for i in 0..16 {
+                        V.push(t * 16 + i).unwrap();
+                    }
+                });
+            }
+        });
+        let mut elements = V.as_slice().to_vec();
+        elements.sort_unstable();
+        assert_eq!(elements, (0..64).collect::<Vec<_>>());
+        assert!(V.push(64).is_err());
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+        assert_send::<AbaoArrayVec<u8, 4>>();
+        assert_sync::<AbaoArrayVec<u8, 4>>();
+        assert_send::<AbaoArrayVec<std::cell::Cell<u8>, 4>>();
+        assert_sync::<AbaoArrayVec<std::sync::Arc<u8>, 4>>();
+    }
+}
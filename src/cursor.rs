@@ -0,0 +1,92 @@
+use crate::storage::Storage;
+use crate::vec::AbaoVecInner;
+
+/// A reading position in a vector, which can be polled for new elements.
+///
+/// Other than an iterator from `iter`, a cursor is not bound to
+/// the length at the time it was created.
+/// Once it caught up with the confirmed elements, `next` returns `None`,
+/// but polling it again later yields the elements pushed in the meantime.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u32>; 8] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+/// let mut cursor = v.cursor();
+///
+/// v.push(1).unwrap();
+/// assert_eq!(cursor.next(), Some(&1));
+/// assert_eq!(cursor.next(), None);
+///
+/// v.push(2).unwrap();
+/// assert_eq!(cursor.next(), Some(&2));
+/// assert_eq!(cursor.position(), 2);
+/// ```
+pub struct AbaoCursor<'v, T, S: Storage<T>> {
+    vec: &'v AbaoVecInner<T, S>,
+    /// index of the next element to yield
+    idx: usize,
+}
+
+impl<'v, T, S: Storage<T>> AbaoCursor<'v, T, S> {
+    pub(crate) fn new(vec: &'v AbaoVecInner<T, S>) -> Self {
+        Self { vec, idx: 0 }
+    }
+
+    /// Returns the next element if it is confirmed already
+    /// and moves past it.
+    ///
+    /// Returns `None` if the cursor caught up with the vector,
+    /// polling again later may yield further elements.
+    /// Yielded elements are never yielded again.
+    // NOTE(naming):
+    // this is no `Iterator`, which should not yield anything after `None`
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&'v T> {
+        let t = self.vec.get(self.idx)?;
+        self.idx += 1;
+        Some(t)
+    }
+
+    /// Returns the index of the next element to yield,
+    /// i.e. the number of elements yielded so far.
+    pub fn position(&self) -> usize {
+        self.idx
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::AbaoVec;
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn resume_after_push() {
+        let mut buf: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let mut cursor = v.cursor();
+        assert_eq!(cursor.next(), None);
+        for i in 0..3 {
+            v.push(i.to_string()).unwrap();
+        }
+        let mut seen = Vec::new();
+        while let Some(s) = cursor.next() {
+            seen.push(s.as_str());
+        }
+        assert_eq!(seen, ["0", "1", "2"]);
+        assert_eq!(cursor.next(), None);
+
+        v.push("3".to_string()).unwrap();
+        v.push("4".to_string()).unwrap();
+        assert_eq!(cursor.next().map(String::as_str), Some("3"));
+        assert_eq!(cursor.next().map(String::as_str), Some("4"));
+        assert_eq!(cursor.next(), None);
+        assert_eq!(cursor.position(), 5);
+    }
+}
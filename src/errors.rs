@@ -1,5 +1,4 @@
-use std::error;
-use std::fmt;
+use core::fmt;
 
 /// Error type which is returned when an insert operation
 /// does not succeed due to the underlaying buffer being exhausted.
@@ -12,4 +11,63 @@ impl fmt::Display for OomError {
     }
 }
 
-impl error::Error for OomError {}
+#[cfg(feature = "std")]
+impl std::error::Error for OomError {}
+
+/// Converts the error into an I/O error of kind `WriteZero`,
+/// which wraps the `OomError`.
+///
+/// The wrapped error can be recovered with `get_ref` or `into_inner`
+/// of the I/O error.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::io;
+/// use std::mem::MaybeUninit;
+///
+/// fn header(v: &AbaoVec<'_, u8>) -> io::Result<()> {
+///     v.extend_from_slice(b"abao")?;
+///     v.push_with(|| 1)?;
+///     Ok(())
+/// }
+///
+/// let mut buf: [MaybeUninit<u8>; 8] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// header(&v).unwrap();
+/// assert_eq!(header(&v).unwrap_err().kind(), io::ErrorKind::WriteZero);
+/// ```
+#[cfg(feature = "std")]
+impl From<OomError> for std::io::Error {
+    fn from(e: OomError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::WriteZero, e)
+    }
+}
+
+#[cfg(all(test, feature = "std", not(loom)))]
+mod tests {
+    use crate::OomError;
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn into_io_error() {
+        let e = io::Error::from(OomError);
+        assert_eq!(e.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(e.to_string(), OomError.to_string());
+        // the wrapped error is the payload, not the source of the I/O error
+        let inner = e.get_ref().unwrap();
+        assert_eq!(inner.downcast_ref::<OomError>(), Some(&OomError));
+        assert!(e.source().is_none());
+        assert_eq!(
+            e.into_inner().unwrap().downcast::<OomError>().ok(),
+            Some(Box::new(OomError))
+        );
+    }
+}
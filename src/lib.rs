@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_docs)]
 #![deny(rust_2018_compatibility)]
 #![deny(rust_2018_idioms)]
@@ -7,12 +8,41 @@
 //!
 //! Append only array backed data structures
 //!
+//! The crate is `no_std`.
+//! The `std` feature (enabled by default) implements `std::error::Error`
+//! for the errors and enables the types which need locks.
+//! The `alloc` feature enables the types which allocate their buffers.
+//! The `cache-padded` feature (enabled by default) puts the counters
+//! written by pushes on separate cache lines, at the cost of a few
+//! hundred bytes per vector.
+//! The `strict-invariants` feature checks internal invariants
+//! of the hot paths in debug builds, at the cost of extra atomic loads.
+//!
 
-// TODO: move to no_std
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+mod array;
+mod cursor;
 mod errors;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "std")]
+mod pool;
+mod storage;
+mod sync;
 mod utils;
 mod vec;
 
+pub use array::AbaoArrayVec;
+pub use cursor::AbaoCursor;
 pub use errors::OomError;
-pub use vec::AbaoVec;
+#[cfg(feature = "alloc")]
+pub use owned::{IntoIter, OwnedAbaoVec};
+#[cfg(feature = "std")]
+pub use pool::{AbaoVecPool, PooledAbaoVec};
+#[cfg(feature = "alloc")]
+pub use storage::BoxStorage;
+pub use storage::{ArrayStorage, SliceStorage, Storage};
+pub use utils::capacity_for;
+pub use vec::{AbaoVec, AbaoVecInner, ReservedSlots};
@@ -0,0 +1,242 @@
+use alloc::boxed::Box;
+use core::cell::Cell;
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+
+use crate::errors::OomError;
+use crate::storage::BoxStorage;
+use crate::vec::AbaoVecInner;
+
+/// An append only vector owning a heap allocated buffer.
+///
+/// Behaves exactly like `AbaoVec`, but allocates its buffer itself
+/// and frees it again when it is dropped.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use abao::OwnedAbaoVec;
+///
+/// let v = OwnedAbaoVec::with_capacity(128);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(v.len(), 3);
+/// assert_eq!(v.as_slice(), &[0, 1, 2]);
+/// ```
+pub type OwnedAbaoVec<T> = AbaoVecInner<T, BoxStorage<T>>;
+
+impl<T> OwnedAbaoVec<T> {
+    /// Creates a new empty vector with a heap allocated buffer
+    /// for `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::OwnedAbaoVec;
+    /// use abao::OomError;
+    ///
+    /// let v = OwnedAbaoVec::with_capacity(1);
+    ///
+    /// assert_eq!(v.push(0), Ok(0));
+    /// assert_eq!(v.push(1), Err((OomError, 1)));
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let slots = (0..capacity)
+            .map(|_| Cell::new(MaybeUninit::uninit()))
+            .collect();
+        Self::from_storage(BoxStorage { slots })
+    }
+
+    /// Creates a new vector containing clones of the elements of `src`
+    /// with room for `extra` more elements.
+    ///
+    /// Returns `OomError` if the total capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::OwnedAbaoVec;
+    /// use abao::OomError;
+    ///
+    /// let v = OwnedAbaoVec::try_from_slice(&[1, 2, 3], 1).unwrap();
+    /// assert_eq!(v.as_slice(), &[1, 2, 3]);
+    ///
+    /// assert_eq!(v.push(4), Ok(3));
+    /// assert_eq!(v.push(5), Err((OomError, 5)));
+    /// ```
+    pub fn try_from_slice(src: &[T], extra: usize) -> Result<Self, OomError>
+    where
+        T: Clone,
+    {
+        let capacity = src.len().checked_add(extra).ok_or(OomError)?;
+        let v = Self::with_capacity(capacity);
+        v.extend_exact(src.iter().cloned())?;
+        Ok(v)
+    }
+}
+
+impl<T> IntoIterator for OwnedAbaoVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates an iterator moving the elements out of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::OwnedAbaoVec;
+    ///
+    /// let v = OwnedAbaoVec::with_capacity(8);
+    /// v.push(String::from("a")).unwrap();
+    /// v.push(String::from("b")).unwrap();
+    ///
+    /// let strings: Vec<String> = v.into_iter().collect();
+    /// assert_eq!(strings, ["a", "b"]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        let (storage, len) = self.into_storage();
+        IntoIter {
+            slots: storage.slots,
+            idx: 0,
+            end: len,
+        }
+    }
+}
+
+/// An iterator moving the elements out of an `OwnedAbaoVec`.
+///
+/// Elements which are not consumed are dropped together with the iterator,
+/// which then frees the buffer.
+pub struct IntoIter<T> {
+    /// the buffer of the vector
+    slots: Box<[Cell<MaybeUninit<T>>]>,
+    /// the next element to move out
+    idx: usize,
+    /// one past the last element to move out
+    end: usize,
+}
+
+impl<T> IntoIter<T> {
+    /// Move the element at `idx` out of the buffer.
+    ///
+    /// # Safety
+    /// The slot at `idx` must be initialized
+    /// and must not be read again afterwards.
+    unsafe fn read_slot(&self, idx: usize) -> T {
+        self.slots.get_unchecked(idx).as_ptr().cast::<T>().read()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            return None;
+        }
+        // NOTE(unsafe):
+        // all slots in `idx..end` are initialized
+        // and the slot is removed from the range right away
+        let t = unsafe { self.read_slot(self.idx) };
+        self.idx += 1;
+        Some(t)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // NOTE(unsafe):
+        // the slot was the last one of the initialized range
+        Some(unsafe { self.read_slot(self.end) })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for t in self.by_ref() {
+            drop(t);
+        }
+        // the buffer itself only holds `MaybeUninit`
+        // and is freed without dropping any slots
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::OwnedAbaoVec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn into_iter_partial() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug)]
+        struct X(usize);
+        impl X {
+            fn new(i: usize) -> X {
+                COUNT.fetch_add(1, Ordering::Relaxed);
+                X(i)
+            }
+        }
+        impl Drop for X {
+            fn drop(&mut self) {
+                COUNT.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+
+        let v = OwnedAbaoVec::with_capacity(8);
+        for i in 0..5 {
+            v.push(X::new(i)).unwrap();
+        }
+        assert_eq!(COUNT.load(Ordering::Relaxed), 5);
+
+        let mut iter = v.into_iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next().map(|x| x.0), Some(0));
+        assert_eq!(iter.next_back().map(|x| x.0), Some(4));
+        assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+        assert_eq!(iter.len(), 3);
+
+        // the remaining elements are dropped with the iterator
+        drop(iter);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let src = ["a".to_string(), "b".to_string()];
+        let v = OwnedAbaoVec::try_from_slice(&src, 0).unwrap();
+        assert_eq!(v.capacity(), 2);
+        assert_eq!(v, src);
+        assert!(v.push("c".to_string()).is_err());
+
+        let v = OwnedAbaoVec::try_from_slice(&src, 2).unwrap();
+        assert_eq!(v.push("c".to_string()), Ok(2));
+        assert_eq!(v.as_slice(), &["a", "b", "c"]);
+
+        assert!(OwnedAbaoVec::try_from_slice(&src, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn into_iter_empty() {
+        let v: OwnedAbaoVec<String> = OwnedAbaoVec::with_capacity(0);
+        assert_eq!(v.into_iter().next(), None);
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::Deref;
+use std::sync::{Mutex, PoisonError};
+
+use crate::owned::OwnedAbaoVec;
+
+/// A pool of `OwnedAbaoVec` buffers for reuse.
+///
+/// Vectors handed out by the pool are returned to it when they are dropped,
+/// so their buffers are allocated only once.
+/// Returned vectors are cleared, dropping all their elements.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVecPool;
+///
+/// let pool = AbaoVecPool::new(128);
+///
+/// let v = pool.acquire();
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// assert_eq!(v.as_slice(), &[0, 1]);
+/// drop(v);
+///
+/// // the buffer is reused, but empty again
+/// let v = pool.acquire();
+/// assert_eq!(v.len(), 0);
+/// ```
+pub struct AbaoVecPool<T> {
+    /// capacity of the vectors in the pool
+    capacity: usize,
+    /// the cleared vectors ready to be handed out again
+    free: Mutex<Vec<OwnedAbaoVec<T>>>,
+}
+
+impl<T> AbaoVecPool<T> {
+    /// Creates a new empty pool of vectors with `capacity` elements each.
+    ///
+    /// No buffer is allocated until a vector is acquired.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            free: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Hands out an empty vector from the pool.
+    ///
+    /// A new vector is allocated if the pool has no free vector.
+    /// The vector returns to the pool when the returned guard is dropped.
+    pub fn acquire(&self) -> PooledAbaoVec<'_, T> {
+        let vec = self
+            .free
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+            .unwrap_or_else(|| OwnedAbaoVec::with_capacity(self.capacity));
+        PooledAbaoVec {
+            pool: self,
+            vec: Some(vec),
+        }
+    }
+
+    /// Number of vectors currently waiting in the pool.
+    pub fn free_len(&self) -> usize {
+        self.free
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+/// A vector handed out by an `AbaoVecPool`.
+///
+/// Dereferences to the `OwnedAbaoVec`.
+/// When dropped, the vector is cleared and returned to the pool.
+pub struct PooledAbaoVec<'p, T> {
+    pool: &'p AbaoVecPool<T>,
+    /// always `Some` until the guard is dropped
+    vec: Option<OwnedAbaoVec<T>>,
+}
+
+impl<T> Deref for PooledAbaoVec<'_, T> {
+    type Target = OwnedAbaoVec<T>;
+
+    fn deref(&self) -> &OwnedAbaoVec<T> {
+        self.vec.as_ref().expect("pooled vector already returned")
+    }
+}
+
+impl<T> Drop for PooledAbaoVec<'_, T> {
+    fn drop(&mut self) {
+        if let Some(mut vec) = self.vec.take() {
+            vec.clear();
+            self.pool
+                .free
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(vec);
+        }
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::AbaoVecPool;
+
+    #[test]
+    fn reuse_buffer() {
+        let pool = AbaoVecPool::new(4);
+        let v = pool.acquire();
+        let ptr = v.as_ptr_range().start;
+        for i in 0..4 {
+            v.push(i.to_string()).unwrap();
+        }
+        assert!(v.push("4".to_string()).is_err());
+        drop(v);
+        assert_eq!(pool.free_len(), 1);
+
+        let v = pool.acquire();
+        assert_eq!(pool.free_len(), 0);
+        assert_eq!(v.as_ptr_range().start, ptr);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.push("a".to_string()), Ok(0));
+        assert_eq!(v.as_slice(), &["a"]);
+
+        // a second vector gets its own buffer
+        let w = pool.acquire();
+        assert_ne!(w.as_ptr_range().start, ptr);
+        drop(v);
+        drop(w);
+        assert_eq!(pool.free_len(), 2);
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+
+use crate::sync::AtomicUsize;
+
+/// Backing memory of a vector.
+///
+/// The vector types of this crate only differ in their storage.
+/// Other backing memory, e.g. from a static pool or an arena,
+/// can be used by implementing this trait
+/// and passing the storage to `AbaoVecInner::from_storage`.
+///
+/// # Safety
+/// The returned slices must stay the same for the lifetime of the storage
+/// and must not be accessed other than through the vector.
+/// If sequence numbers are returned, there must be exactly one per slot.
+/// The vector is `Send` or `Sync` only if the storage is,
+/// so a storage may only be `Send` or `Sync` if its slots
+/// and sequence numbers may be accessed from other threads that way.
+///
+/// # Examples
+///
+/// ```
+/// use abao::{AbaoVecInner, Storage};
+/// use std::cell::Cell;
+/// use std::mem::MaybeUninit;
+/// use std::sync::atomic::AtomicUsize;
+///
+/// struct VecStorage<T>(Vec<Cell<MaybeUninit<T>>>);
+///
+/// unsafe impl<T> Storage<T> for VecStorage<T> {
+///     fn slots(&self) -> &[Cell<MaybeUninit<T>>] {
+///         &self.0
+///     }
+///
+///     fn seq(&self) -> Option<&[AtomicUsize]> {
+///         None
+///     }
+/// }
+///
+/// let slots = (0..4).map(|_| Cell::new(MaybeUninit::uninit())).collect();
+/// let v = AbaoVecInner::from_storage(VecStorage(slots));
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// assert_eq!(v.capacity(), 4);
+/// assert_eq!(v.as_slice(), &[0, 1]);
+/// ```
+pub unsafe trait Storage<T> {
+    /// The slots the elements are stored in.
+    fn slots(&self) -> &[Cell<MaybeUninit<T>>];
+
+    /// The per slot sequence numbers,
+    /// if the vector publishes its elements through them.
+    ///
+    /// They are `core::sync::atomic::AtomicUsize`,
+    /// except when compiled with `--cfg loom`,
+    /// where they are loom's `AtomicUsize`,
+    /// so implementations have to switch the type the same way under loom.
+    fn seq(&self) -> Option<&[AtomicUsize]>;
+}
+
+/// Storage in a borrowed buffer.
+pub struct SliceStorage<'a, T> {
+    pub(crate) slots: &'a [Cell<MaybeUninit<T>>],
+    pub(crate) seq: Option<&'a [AtomicUsize]>,
+}
+
+// NOTE(unsafe):
+// the `Cell`s are only accessed through the vector,
+// which requires the same of its elements, e.g. `T: Send`.
+unsafe impl<T: Send> Send for SliceStorage<'_, T> {}
+// NOTE(unsafe):
+// see `Send`
+unsafe impl<T: Send + Sync> Sync for SliceStorage<'_, T> {}
+
+unsafe impl<'a, T> Storage<T> for SliceStorage<'a, T> {
+    fn slots(&self) -> &[Cell<MaybeUninit<T>>] {
+        self.slots
+    }
+
+    fn seq(&self) -> Option<&[AtomicUsize]> {
+        self.seq
+    }
+}
+
+/// Storage in an owned heap allocation.
+#[cfg(feature = "alloc")]
+pub struct BoxStorage<T> {
+    pub(crate) slots: Box<[Cell<MaybeUninit<T>>]>,
+}
+
+// NOTE(unsafe):
+// see `SliceStorage`
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send + Sync> Sync for BoxStorage<T> {}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T> Storage<T> for BoxStorage<T> {
+    fn slots(&self) -> &[Cell<MaybeUninit<T>>] {
+        &self.slots
+    }
+
+    fn seq(&self) -> Option<&[AtomicUsize]> {
+        None
+    }
+}
+
+/// Storage in an inline array.
+pub struct ArrayStorage<T, const N: usize> {
+    pub(crate) slots: [Cell<MaybeUninit<T>>; N],
+}
+
+// NOTE(unsafe):
+// see `SliceStorage`
+unsafe impl<T: Send + Sync, const N: usize> Sync for ArrayStorage<T, N> {}
+
+unsafe impl<T, const N: usize> Storage<T> for ArrayStorage<T, N> {
+    fn slots(&self) -> &[Cell<MaybeUninit<T>>] {
+        &self.slots
+    }
+
+    fn seq(&self) -> Option<&[AtomicUsize]> {
+        None
+    }
+}
@@ -0,0 +1,99 @@
+//! Synchronization primitives used by the vectors.
+//!
+//! When compiled with `--cfg loom` the primitives of the `loom`
+//! model checker are used instead of the ones from `core`,
+//! so the concurrency protocol can be checked exhaustively.
+
+#[cfg(loom)]
+pub(crate) use loom::hint::spin_loop;
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(not(loom))]
+pub(crate) use core::hint::spin_loop;
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{AtomicUsize, Ordering};
+
+use core::ops::Deref;
+
+/// Aligns a value to its own cache line with the `cache-padded` feature.
+///
+/// Atomics written by different threads are kept apart this way,
+/// so writing one does not invalidate the cache line of the other.
+/// Without the feature the value is not padded,
+/// which saves the memory on small targets.
+///
+/// 128 bytes on x86_64 and aarch64 cover the pair of cache lines
+/// their prefetchers fetch together.
+#[cfg_attr(
+    all(
+        feature = "cache-padded",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ),
+    repr(align(128))
+)]
+#[cfg_attr(
+    all(
+        feature = "cache-padded",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ),
+    repr(align(64))
+)]
+pub(crate) struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    pub(crate) const fn new(t: T) -> Self {
+        Self(t)
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Exponential backoff for spin loops waiting on other threads.
+///
+/// Spins for twice as long on every step,
+/// then yields to the scheduler with the `std` feature,
+/// as the waited for thread may not even be running.
+/// Without `std` it keeps spinning at the longest step.
+pub(crate) struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    /// The last step which only spins.
+    const SPIN_LIMIT: u32 = 6;
+
+    pub(crate) fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    /// Wait a bit longer than on the previous call.
+    pub(crate) fn snooze(&mut self) {
+        // NOTE(loom):
+        // every spin is a yield point for loom,
+        // so a single one keeps the explored state space small
+        if cfg!(loom) {
+            spin_loop();
+            return;
+        }
+        if self.step <= Self::SPIN_LIMIT {
+            for _ in 0..1 << self.step {
+                spin_loop();
+            }
+            self.step += 1;
+            return;
+        }
+        #[cfg(feature = "std")]
+        std::thread::yield_now();
+        #[cfg(not(feature = "std"))]
+        for _ in 0..1 << Self::SPIN_LIMIT {
+            spin_loop();
+        }
+    }
+}
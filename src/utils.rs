@@ -1,4 +1,5 @@
-use std::cell::Cell;
+use core::cell::Cell;
+use core::mem::MaybeUninit;
 
 pub(crate) fn cell_from_mut<T: ?Sized>(t: &mut T) -> &Cell<T> {
     unsafe { &*(t as *mut T as *const Cell<T>) }
@@ -7,3 +8,54 @@ pub(crate) fn cell_from_mut<T: ?Sized>(t: &mut T) -> &Cell<T> {
 pub(crate) fn cell_as_slice_of_cells<T>(cell: &Cell<[T]>) -> &[Cell<T>] {
     unsafe { &*(cell as *const Cell<[T]> as *const [Cell<T>]) }
 }
+
+/// Returns how many elements of type `T` fit into a buffer of `bytes` bytes.
+///
+/// Use this to size a `[MaybeUninit<T>; N]` buffer for a byte budget,
+/// e.g. for a statically placed or DMA buffer.
+/// The size of an element always includes the padding for its alignment,
+/// so the elements fit as long as the buffer itself is aligned for `T`.
+///
+/// Zero sized elements take no memory, so any number of them fits
+/// and `usize::MAX` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use abao::capacity_for;
+///
+/// assert_eq!(capacity_for::<u32>(4096), 1024);
+/// assert_eq!(capacity_for::<u32>(7), 1);
+/// assert_eq!(capacity_for::<()>(0), usize::MAX);
+/// ```
+pub fn capacity_for<T>(bytes: usize) -> usize {
+    match core::mem::size_of::<MaybeUninit<T>>() {
+        0 => usize::MAX,
+        size => bytes / size,
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::capacity_for;
+
+    #[test]
+    fn capacity_for_sizes() {
+        assert_eq!(capacity_for::<u8>(100), 100);
+        assert_eq!(capacity_for::<u64>(100), 12);
+        assert_eq!(capacity_for::<u64>(7), 0);
+        // the padding of the tuple counts as well
+        assert_eq!(capacity_for::<(u8, u32)>(64), 8);
+        assert_eq!(capacity_for::<()>(0), usize::MAX);
+        assert_eq!(capacity_for::<[u8; 0]>(5), usize::MAX);
+    }
+
+    #[test]
+    fn capacity_for_over_aligned() {
+        #[repr(align(64))]
+        struct Line(#[allow(dead_code)] u8);
+        assert_eq!(core::mem::size_of::<Line>(), 64);
+        assert_eq!(capacity_for::<Line>(63), 0);
+        assert_eq!(capacity_for::<Line>(256), 4);
+    }
+}
@@ -1,9 +1,16 @@
-use std::cell::Cell;
-use std::fmt;
-use std::mem::MaybeUninit;
-use std::sync::atomic::{self, AtomicUsize, Ordering};
+use core::borrow::Borrow;
+use core::cell::Cell;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Peekable;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, Index, Range, RangeFrom, RangeFull, RangeTo};
 
+use crate::cursor::AbaoCursor;
 use crate::errors::OomError;
+use crate::storage::{SliceStorage, Storage};
+use crate::sync::{AtomicUsize, Backoff, CachePadded, Ordering};
 use crate::utils::{cell_as_slice_of_cells, cell_from_mut};
 
 /// An array backed apend only vector.
@@ -30,13 +37,30 @@ use crate::utils::{cell_as_slice_of_cells, cell_from_mut};
 /// assert_eq!(v.get(1), Some(&1));
 /// assert_eq!(v.get(2), Some(&2));
 /// ```
-pub struct AbaoVec<'a, T> {
+pub type AbaoVec<'a, T> = AbaoVecInner<T, SliceStorage<'a, T>>;
+
+/// An append only vector over some storage.
+///
+/// This is the common implementation of `AbaoVec`
+/// and the other vector types, which only differ in their storage.
+/// It can be used directly with a custom `Storage`.
+pub struct AbaoVecInner<T, S: Storage<T>> {
     /// the next index to write to
-    next_idx: AtomicUsize,
+    next_idx: CachePadded<AtomicUsize>,
     /// length of continous initialized elements
-    confirmed_len: AtomicUsize,
-    /// backing buffer
-    buf: &'a [Cell<MaybeUninit<T>>],
+    confirmed_len: CachePadded<AtomicUsize>,
+    /// number of slots which may still be confirmed,
+    /// lowered when a claimed block is abandoned before it is filled.
+    /// it is mostly read, so it does not need its own cache line
+    limit: AtomicUsize,
+    /// the highest length before the length was last lowered.
+    /// the length only grows in between, so the high water mark
+    /// is the larger one of this and the current length
+    high_water: usize,
+    /// backing buffer and sequence numbers.
+    /// a slot at index `i` is published once its sequence number is `i + 1`
+    storage: S,
+    _marker: PhantomData<T>,
 }
 
 impl<'a, T> AbaoVec<'a, T> {
@@ -64,10 +88,257 @@ impl<'a, T> AbaoVec<'a, T> {
     /// assert_eq!(v.len(), 0);
     /// ```
     pub fn new(buf: &'a mut [MaybeUninit<T>]) -> Self {
+        Self::from_storage(SliceStorage {
+            slots: cell_as_slice_of_cells(cell_from_mut(buf)),
+            seq: None,
+        })
+    }
+
+    /// Creates a new empty vector with the given buffer as backing memory,
+    /// after filling the buffer with zero bytes.
+    ///
+    /// This is for buffers which have to hold well defined bytes,
+    /// e.g. because hardware or foreign code reads them.
+    /// The vector still starts empty, the zeroed slots are not
+    /// elements of the vector until they are pushed,
+    /// even if zero is a valid value of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [const { MaybeUninit::uninit() }; 4];
+    /// let v = AbaoVec::new_zeroed(&mut buf);
+    ///
+    /// assert_eq!(v.len(), 0);
+    /// v.push(7_u32).unwrap();
+    /// assert_eq!(v.as_slice(), &[7]);
+    /// drop(v);
+    ///
+    /// // the slots which were never pushed to are still zeroed
+    /// assert_eq!(unsafe { buf[1].assume_init() }, 0);
+    /// ```
+    pub fn new_zeroed(buf: &'a mut [MaybeUninit<T>]) -> Self {
+        // NOTE(unsafe):
+        // the slots are `MaybeUninit`, so any bytes are fine for them
+        unsafe { core::ptr::write_bytes(buf.as_mut_ptr(), 0, buf.len()) };
+        Self::new(buf)
+    }
+
+    /// Creates a new empty vector with the given array as backing memory.
+    ///
+    /// Behaves like `new`, but the capacity is part of the type
+    /// and an array of uninitialized slots can be created without `unsafe`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [const { MaybeUninit::uninit() }; 128];
+    /// let v = AbaoVec::from_array(&mut buf);
+    ///
+    /// v.push(0_u8).unwrap();
+    /// assert_eq!(v.capacity(), 128);
+    /// assert_eq!(v.as_slice(), &[0]);
+    /// ```
+    pub fn from_array<const N: usize>(buf: &'a mut [MaybeUninit<T>; N]) -> Self {
+        Self::new(&mut buf[..])
+    }
+
+    /// Creates a new empty vector which publishes elements
+    /// through per slot sequence numbers.
+    ///
+    /// A vector created by `new` confirms its elements strictly in order,
+    /// so every `push` has to wait for all earlier pushes to finish.
+    /// A vector created by `new_seq` instead publishes each element
+    /// by storing its sequence number in `seq`, so a `push` never waits
+    /// for other pushes. The confirmed length is advanced over
+    /// the published prefix by `push` and `len`.
+    /// `get` can observe a published element even before
+    /// all elements before it are published.
+    ///
+    /// If the vector is sealed, e.g. by a short iterator in `extend_exact`,
+    /// pushes after the sealed block fail and hand back their value.
+    /// Only a push which publishes its element while the block before it
+    /// is being abandoned may still succeed, its element then stays
+    /// out of the length of the vector.
+    ///
+    /// The capacity of the vector is the smaller length of `buf` and `seq`.
+    /// All sequence numbers are reset when creating the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    /// use std::sync::atomic::AtomicUsize;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut seq = [
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    /// ];
+    /// let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    ///
+    /// assert_eq!(v.len(), 2);
+    /// assert_eq!(v.as_slice(), &[0, 1]);
+    /// ```
+    pub fn new_seq(buf: &'a mut [MaybeUninit<T>], seq: &'a mut [AtomicUsize]) -> Self {
+        let cap = buf.len().min(seq.len());
+        Self::from_storage(SliceStorage {
+            slots: cell_as_slice_of_cells(cell_from_mut(&mut buf[..cap])),
+            seq: Some(&seq[..cap]),
+        })
+    }
+
+    /// Splits the vector into its elements and the rest of the buffer,
+    /// both borrowed for the lifetime of the buffer.
+    ///
+    /// The elements are not dropped, they are owned by the caller afterwards.
+    /// Published elements after a gap are not part of the vector
+    /// and are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    ///
+    /// let (elements, spare) = v.into_parts();
+    /// elements[0] = 7;
+    /// assert_eq!(elements, &[7, 1]);
+    /// assert_eq!(spare.len(), 2);
+    /// ```
+    pub fn into_parts(self) -> (&'a mut [T], &'a mut [MaybeUninit<T>]) {
+        let (storage, len) = self.into_storage();
+        let slots = storage.slots;
+        // NOTE(unsafe):
+        // the slots were borrowed mutably for `'a` when creating the vector,
+        // which is gone now without dropping its elements,
+        // so nothing else accesses them anymore.
+        // the first `len` slots are initialized
+        unsafe {
+            let ptr = slots.as_ptr() as *mut MaybeUninit<T>;
+            (
+                core::slice::from_raw_parts_mut(ptr.cast::<T>(), len),
+                core::slice::from_raw_parts_mut(ptr.add(len), slots.len() - len),
+            )
+        }
+    }
+
+    /// Splits off the rest of the buffer as a new empty vector.
+    ///
+    /// The elements are returned like by `into_parts`,
+    /// so one buffer can back several vectors one after another.
+    /// The new vector confirms its elements in order like one created by `new`,
+    /// even if this vector has sequence numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push(0).unwrap();
+    ///
+    /// let (first, v) = v.split_spare();
+    /// v.push(1).unwrap();
+    /// assert_eq!(v.capacity(), 3);
+    ///
+    /// assert_eq!(first, &[0]);
+    /// assert_eq!(v.as_slice(), &[1]);
+    /// ```
+    pub fn split_spare(self) -> (&'a mut [T], AbaoVec<'a, T>) {
+        let (elements, spare) = self.into_parts();
+        (elements, AbaoVec::new(spare))
+    }
+
+    /// Consumes the vector and returns its elements
+    /// borrowed for the lifetime of the buffer.
+    ///
+    /// Other than `as_slice`, the slice outlives the vector.
+    /// The elements are never dropped then, they are leaked in the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let frozen = {
+    ///     let v = AbaoVec::new(&mut buf[..]);
+    ///     v.push(0).unwrap();
+    ///     v.push(1).unwrap();
+    ///     v.freeze()
+    /// };
+    ///
+    /// assert_eq!(frozen, &[0, 1]);
+    /// ```
+    #[doc(alias = "leak")]
+    pub fn freeze(self) -> &'a [T] {
+        self.into_parts().0
+    }
+}
+
+impl<T, S: Storage<T>> AbaoVecInner<T, S> {
+    /// Creates a new empty vector in the given storage.
+    ///
+    /// The capacity of the vector is the number of slots of the storage.
+    /// All sequence numbers of the storage are reset.
+    pub fn from_storage(storage: S) -> Self {
+        let cap = storage.slots().len();
+        for s in storage.seq().unwrap_or_default() {
+            s.store(0, Ordering::Relaxed);
+        }
         Self {
-            next_idx: AtomicUsize::new(0),
-            confirmed_len: AtomicUsize::new(0),
-            buf: cell_as_slice_of_cells(cell_from_mut(buf)),
+            next_idx: CachePadded::new(AtomicUsize::new(0)),
+            confirmed_len: CachePadded::new(AtomicUsize::new(0)),
+            limit: AtomicUsize::new(cap),
+            high_water: 0,
+            storage,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new empty vector in the given storage of `cap` slots
+    /// in const context.
+    ///
+    /// `cap` must be the number of slots of the storage.
+    /// Not available with loom, whose atomics are not const.
+    #[cfg(not(loom))]
+    pub(crate) const fn from_storage_const(storage: S, cap: usize) -> Self {
+        Self {
+            next_idx: CachePadded::new(AtomicUsize::new(0)),
+            confirmed_len: CachePadded::new(AtomicUsize::new(0)),
+            limit: AtomicUsize::new(cap),
+            high_water: 0,
+            storage,
+            _marker: PhantomData,
         }
     }
 
@@ -77,6 +348,9 @@ impl<'a, T> AbaoVec<'a, T> {
     /// which have not finished to be inserted.
     /// However this is the guaranteed minimal length of the vector.
     ///
+    /// Every element below the returned length can be read by `get`,
+    /// including everything its pushing thread did before the `push`.
+    ///
     /// # Exmaples
     ///
     /// ```
@@ -97,18 +371,47 @@ impl<'a, T> AbaoVec<'a, T> {
     /// assert_eq!(v.len(), 3);
     /// ```
     pub fn len(&self) -> usize {
-        let len = self.confirmed_len.load(Ordering::Relaxed);
-        debug_assert!(
-            len <= self.buf.len(),
-            "Invariant violation: Vector longer than buffer"
-        );
+        let len = self.effective_len();
+        // NOTE(invariant):
+        // checking against the next index costs a second load on every call,
+        // so it is only done with the `strict-invariants` feature
+        #[cfg(any(test, feature = "strict-invariants"))]
         debug_assert!(
             len <= self.next_idx.load(Ordering::Relaxed),
-            "Invarian violation: Vector has more confirmed writes than total writes"
+            "Invariant violation: Vector has more confirmed writes than total writes"
         );
         len
     }
 
+    /// Get a hint of the current length of the vector.
+    ///
+    /// The length is loaded relaxed and not advanced over published slots,
+    /// so it may lag behind `len`.
+    /// Elements below it can not be read based on it alone,
+    /// use `len` or `get` for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// assert_eq!(v.len_relaxed(), 1);
+    /// ```
+    pub fn len_relaxed(&self) -> usize {
+        // NOTE(ordering):
+        // nothing is read based on the length, so it needs no synchronization
+        self.confirmed_len
+            .load(Ordering::Relaxed)
+            .min(self.buf().len())
+    }
+
     /// Check if the vector is currently empty.
     ///
     /// Actually the vector may already contain some elements
@@ -134,13 +437,10 @@ impl<'a, T> AbaoVec<'a, T> {
         self.len() == 0
     }
 
-    /// Get the value at index `idx`.
-    ///
-    /// Returns `None` if the index is out of bounds of the vector.
+    /// Get the number of elements the vector can hold in total.
     ///
-    /// Only compleated `push` operations can increase the readable length
-    /// of the vector. Therfore only `get` operations are consistent,
-    /// even while `push` operations may be performed conrurrently.
+    /// This is the length of the buffer, even for zero sized elements.
+    /// Such a buffer takes no memory, so it may be as long as `usize::MAX`.
     ///
     /// # Examples
     ///
@@ -153,49 +453,53 @@ impl<'a, T> AbaoVec<'a, T> {
     /// };
     /// let v = AbaoVec::new(&mut buf[..]);
     ///
-    /// v.push(0).unwrap();
-    /// v.push(1).unwrap();
-    /// v.push(2).unwrap();
-    ///
-    /// assert_eq!(v.get(0), Some(&0));
-    /// assert_eq!(v.get(1), Some(&1));
-    /// assert_eq!(v.get(2), Some(&2));
-    /// assert_eq!(v.get(3), None);
-    /// assert_eq!(v.get(128), None);
+    /// assert_eq!(v.capacity(), 128);
     /// ```
-    pub fn get(&self, idx: usize) -> Option<&T> {
-        if idx >= self.len() {
-            return None;
-        }
-        unsafe {
-            // NOTE(unsafe):
-            // since all elements up to at least the current len
-            // have been initialized
-            // and idx is not out of bounds, this is safe to do
-            Some(self.get_unchecked(idx))
-        }
+    pub fn capacity(&self) -> usize {
+        self.buf().len()
     }
 
-    /// Get the value at index `idx` without checking bounds.
+    /// Get the highest length the vector ever had.
     ///
-    /// # Safety
-    /// An index that is out of bounds of this vector can cause creating
-    /// a reference to uninitialized memory within the underlaying buffer
-    /// or even outside of the underlaying buffer.
-    /// This is generally undefined behavior.
-    pub unsafe fn get_unchecked(&self, idx: usize) -> &T {
-        // NOTE(unsafe):
-        // only safe when idx is not out of bounds of initialized elements
-        let cell_ptr = self.buf.get_unchecked(idx).as_ptr() as *const MaybeUninit<T>;
-        &*(*cell_ptr).as_ptr()
+    /// Other than the length, this is not lowered by `clear`,
+    /// so it tells how much of the buffer was ever used,
+    /// e.g. for deciding if the buffer is sized right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 8] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.extend_from_slice(&[0, 1, 2]).unwrap();
+    /// v.clear();
+    /// v.push(0).unwrap();
+    ///
+    /// assert_eq!(v.len(), 1);
+    /// assert_eq!(v.high_water_mark(), 3);
+    /// ```
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.max(self.len())
     }
 
-    /// TODO: write doc
+    /// Get the number of slots claimed by pushes, saturated at the capacity.
+    ///
+    /// This includes pushes which have not finished yet,
+    /// so `claimed_len() - len()` is the number of slots
+    /// currently in flight, e.g. for diagnostics or backpressure.
+    /// Slots reserved by `reserve` count as claimed until they are committed.
+    /// Like `len`, this is only a snapshot, which other threads
+    /// may change right after it was taken.
+    ///
+    /// # Examples
     ///
-    /// # Eaxmples
     /// ```
     /// use abao::AbaoVec;
-    /// use abao::OomError;
     /// use std::mem::MaybeUninit;
     ///
     /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
@@ -203,207 +507,4478 @@ impl<'a, T> AbaoVec<'a, T> {
     /// };
     /// let v = AbaoVec::new(&mut buf[..]);
     ///
-    /// assert_eq!(v.push(0), Ok(0));
-    /// assert_eq!(v.push(1), Ok(1));
-    /// assert_eq!(v.push(2), Ok(2));
-    /// assert_eq!(v.push(3), Ok(3));
-    /// assert_eq!(v.push(4), Err(OomError));
+    /// v.push(0).unwrap();
+    /// v.reserve(2).unwrap();
+    /// assert_eq!(v.claimed_len(), 3);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn claimed_len(&self) -> usize {
+        self.next_idx.load(Ordering::Acquire).min(self.capacity())
+    }
+
+    /// Get the confirmed length and the claimed length in one snapshot.
     ///
-    /// assert_eq!(v.as_slice(), &[0, 1, 2, 3])
+    /// Other than two separate calls of `claimed_len` and `len`,
+    /// the returned confirmed length is never greater than the claimed one.
+    /// Loading the claimed length first might miss pushes which
+    /// are confirmed by the time the confirmed length is loaded.
+    /// This loads the confirmed length first instead,
+    /// and with acquire the claims of the confirmed pushes are visible then,
+    /// so the difference is the number of slots in flight.
+    ///
+    /// # Examples
     ///
     /// ```
-    pub fn push(&self, t: T) -> Result<usize, OomError> {
-        // 1. claim the next index to write to by increasing it
-        // this ensures that only the current push
-        // can access the memory at the claimed location
-
-        let idx = self.next_idx.fetch_add(1, Ordering::SeqCst); // can this be weaker?
-
-        if idx >= self.buf.len() {
-            // prevent usize overflow
-            self.next_idx.store(self.buf.len(), Ordering::Relaxed); // should this be stronger?
-            return Err(OomError);
-        }
-
-        // 2. write to the claimed index
-
-        unsafe {
-            // NOTE(unsafe):
-            // TODO: write safty note
-            let cell_ptr = self.buf.get_unchecked(idx).as_ptr();
-            let ptr: *mut T = (&mut *cell_ptr).as_mut_ptr();
-            std::ptr::write(ptr, t);
-        }
-
-        // 3. increase the confirmed length to be the next index after this,
-        // but only if all previous writes have finished.
-        // it may be only increased by one.
-        // this ensures that read calls can only access
-        // completely initialized memory.
-
-        let expected_current = idx;
-        let new_confirmed = idx + 1;
-
-        // NOTE(spinlock):
-        // TODO: Write spinlock note
-        while self
-            .confirmed_len
-            .compare_exchange(
-                expected_current,
-                new_confirmed,
-                Ordering::SeqCst,
-                Ordering::SeqCst, // can this be weaker?
-            )
-            .is_err()
-        {
-            atomic::spin_loop_hint()
-        }
-
-        Ok(idx)
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// v.reserve(2).unwrap();
+    /// assert_eq!(v.snapshot(), (1, 3));
+    /// ```
+    pub fn snapshot(&self) -> (usize, usize) {
+        let confirmed = self.len();
+        // NOTE(overflow):
+        // both are saturated at the capacity and the next index
+        // may have been corrupted, so keep the promise regardless
+        let claimed = self.claimed_len().max(confirmed);
+        (confirmed, claimed)
     }
 
-    /// Extracts a slice containing the entire vector up to the current length.
+    /// Get the number of slots which have not been claimed by any push yet.
     ///
-    /// This slice does not include elements that are currently being inserted.
-    /// However it contains only fully inserted elements.
+    /// This is measured against the claimed slots,
+    /// including pushes which have not finished yet,
+    /// so this many further pushes will succeed
+    /// unless other threads push concurrently.
+    /// Under concurrency it is only a hint,
+    /// since other threads may claim slots between reading the headroom
+    /// and a subsequent `push`.
+    /// It is 0 once the vector is full or was sealed.
     ///
     /// # Examples
+    ///
     /// ```
     /// use abao::AbaoVec;
     /// use std::mem::MaybeUninit;
     ///
-    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    /// let mut buf: [MaybeUninit<u8>; 2] = unsafe {
     ///     MaybeUninit::uninit().assume_init()
     /// };
     /// let v = AbaoVec::new(&mut buf[..]);
     ///
-    /// assert_eq!(v.as_slice(), &[]);
-    ///
+    /// assert_eq!(v.headroom(), 2);
     /// v.push(0).unwrap();
+    /// assert_eq!(v.headroom(), 1);
     /// v.push(1).unwrap();
-    /// v.push(2).unwrap();
-    ///
-    /// assert_eq!(v.as_slice(), &[0, 1, 2]);
+    /// assert_eq!(v.headroom(), 0);
+    /// assert!(v.push(2).is_err());
     /// ```
-    pub fn as_slice(&self) -> &[T] {
-        // NOTE(unsafe):
-        // TODO: write safety note
-        // NOTE(index):
-        // self.len() should never be out of bound,
-        // so checking the index is actually not necessary
-        // TODO: remove checked indexing
-        unsafe { &*(&self.buf[0..self.len()] as *const [Cell<MaybeUninit<T>>] as *const [T]) }
+    #[doc(alias = "remaining")]
+    pub fn headroom(&self) -> usize {
+        // NOTE(overflow):
+        // sealing the vector may lower the limit below the next index
+        self.limit
+            .load(Ordering::Acquire)
+            .saturating_sub(self.next_idx.load(Ordering::Acquire))
     }
-}
 
-impl<'a, T> Drop for AbaoVec<'a, T> {
-    fn drop(&mut self) {
-        for cell in &self.buf[0..self.len()] {
-            // NOTE(unsafe):
-            unsafe {
+    /// Check if no more slots can be claimed, so every `push` fails.
+    ///
+    /// This is the case if `headroom` is zero,
+    /// so the slots of unfinished pushes count as taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 2] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// assert!(!v.is_full());
+    /// v.push(1).unwrap();
+    /// assert!(v.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.headroom() == 0
+    }
+
+    /// Get the number of slots after the current length of the vector.
+    ///
+    /// This is measured against the confirmed elements,
+    /// so it also counts slots of pushes which have not finished yet.
+    /// Without concurrent pushes it is never smaller than `headroom`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// assert_eq!(v.remaining_capacity(), 4);
+    /// v.push(0).unwrap();
+    /// assert_eq!(v.remaining_capacity(), 3);
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Get the value at index `idx`.
+    ///
+    /// Returns `None` if the index is out of bounds of the vector.
+    ///
+    /// Only compleated `push` operations can increase the readable length
+    /// of the vector. Therfore only `get` operations are consistent,
+    /// even while `push` operations may be performed conrurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// assert_eq!(v.get(0), Some(&0));
+    /// assert_eq!(v.get(1), Some(&1));
+    /// assert_eq!(v.get(2), Some(&2));
+    /// assert_eq!(v.get(3), None);
+    /// assert_eq!(v.get(128), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx >= self.effective_len() && !self.is_published(idx) {
+            return None;
+        }
+        unsafe {
+            // NOTE(unsafe):
+            // since all elements up to at least the current len
+            // have been initialized
+            // and idx is not out of bounds, this is safe to do
+            Some(self.get_unchecked(idx))
+        }
+    }
+
+    /// Get the value at index `idx`, waiting until it is confirmed.
+    ///
+    /// Returns `None` right away if the slot can never be confirmed,
+    /// because it is out of the capacity or the vector was sealed before it.
+    /// Otherwise this spins with a backoff until the element arrives.
+    ///
+    /// This is meant for a consumer following a producer,
+    /// which knows that the element will be pushed.
+    /// If no push ever reaches `idx`, this waits forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    /// use std::thread;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = &AbaoVec::new(&mut buf[..]);
+    ///
+    /// thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         for i in 0..4 {
+    ///             v.push(i * 10).unwrap();
+    ///         }
+    ///     });
+    ///     assert_eq!(v.get_blocking(3), Some(&30));
+    /// });
+    /// assert_eq!(v.get_blocking(4), None);
+    /// ```
+    pub fn get_blocking(&self, idx: usize) -> Option<&T> {
+        let mut backoff = Backoff::new();
+        loop {
+            if let Some(t) = self.get(idx) {
+                return Some(t);
+            }
+            if idx >= self.limit.load(Ordering::Acquire) {
+                // the limit is lowered only after the elements before it
+                // are confirmed, so a second look is conclusive
+                return self.get(idx);
+            }
+            backoff.snooze();
+        }
+    }
+
+    /// Get the elements in `range` as a slice.
+    ///
+    /// Returns `None` if the range reaches past the current length
+    /// or its start is after its end.
+    /// The length is loaded once, just like for `as_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// for i in 0..4 {
+    ///     v.push(i).unwrap();
+    /// }
+    ///
+    /// assert_eq!(v.get_range(1..3), Some(&[1, 2][..]));
+    /// assert_eq!(v.get_range(2..5), None);
+    /// assert_eq!(v.get_range(4..4), Some(&[][..]));
+    /// ```
+    #[doc(alias = "subslice")]
+    pub fn get_range(&self, range: Range<usize>) -> Option<&[T]> {
+        self.as_slice().get(range)
+    }
+
+    /// Get the value at index `idx` without checking bounds.
+    ///
+    /// # Safety
+    /// An index that is out of bounds of this vector can cause creating
+    /// a reference to uninitialized memory within the underlaying buffer
+    /// or even outside of the underlaying buffer.
+    /// This is generally undefined behavior.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> &T {
+        // NOTE(unsafe):
+        // the caller guarantees the slot is in bounds and initialized.
+        // an initialized slot is never written again while the vector
+        // is shared, so handing out a shared reference is fine
+        &*self.slot_ptr(idx)
+    }
+
+    /// Get the first element of the vector, or `None` if it is empty.
+    ///
+    /// Once confirmed, the first element never changes,
+    /// so the returned reference stays the same under concurrent pushes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// assert_eq!(v.first(), None);
+    ///
+    /// v.push(7).unwrap();
+    /// v.push(8).unwrap();
+    /// assert_eq!(v.first(), Some(&7));
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        if self.effective_len() == 0 {
+            return None;
+        }
+        // NOTE(unsafe):
+        // the first element is below the loaded length
+        unsafe { Some(self.get_unchecked(0)) }
+    }
+
+    /// Get mutable access to the value at index `idx`.
+    ///
+    /// This needs exclusive access, so no other thread can read
+    /// or push while the element is changed.
+    /// Only elements up to the current length are accessible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// *v.get_mut(0).unwrap() = 7;
+    /// assert_eq!(v.get_mut(1), None);
+    /// assert_eq!(v.as_slice(), &[7]);
+    /// ```
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(idx)
+    }
+
+    /// Extracts a mutable slice of the entire vector up to the current length.
+    ///
+    /// This needs exclusive access, so no other thread can read
+    /// or push while the elements are changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(2).unwrap();
+    /// v.push(1).unwrap();
+    /// v.as_mut_slice().sort();
+    /// assert_eq!(v.as_slice(), &[1, 2]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.effective_len();
+        // NOTE(unsafe):
+        // all elements below the length are initialized
+        // and the exclusive borrow rules out any other access
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), len) }
+    }
+
+    /// Get the last element of the vector, or `None` if it is empty.
+    ///
+    /// The length is loaded only once, so the element is the last one
+    /// of the same snapshot, even while other threads are pushing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// assert_eq!(v.last(), None);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    /// assert_eq!(v.last(), Some(&2));
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        let len = self.effective_len();
+        let idx = len.checked_sub(1)?;
+        // NOTE(unsafe):
+        // all elements below the loaded length are initialized
+        unsafe { Some(self.get_unchecked(idx)) }
+    }
+
+    /// TODO: write doc
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full, `OomError` is returned
+    /// together with the value, which is handed back to the caller.
+    ///
+    /// # Panic safety
+    ///
+    /// The value is fully constructed by the caller before `push` is entered,
+    /// so no user code runs between claiming a slot and confirming it.
+    /// A panic while producing the value (e.g. in `Clone`) happens
+    /// before any slot is claimed and leaves the vector untouched.
+    /// A panic in between would leave the slot unconfirmed,
+    /// so all later pushes would wait for it forever.
+    /// `push_with` guards against that for values constructed in between.
+    ///
+    /// # Eaxmples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// assert_eq!(v.push(0), Ok(0));
+    /// assert_eq!(v.push(1), Ok(1));
+    /// assert_eq!(v.push(2), Ok(2));
+    /// assert_eq!(v.push(3), Ok(3));
+    /// assert_eq!(v.push(4), Err((OomError, 4)));
+    ///
+    /// assert_eq!(v.as_slice(), &[0, 1, 2, 3])
+    ///
+    /// ```
+    pub fn push(&self, t: T) -> Result<usize, (OomError, T)> {
+        // 1. claim the next index to write to by increasing it
+        // this ensures that only the current push
+        // can access the memory at the claimed location.
+        // the index is never increased past the limit,
+        // so failing pushes can not overflow it
+
+        let idx = match self.claim(1) {
+            Some(idx) => idx,
+            None => return Err((OomError, t)),
+        };
+
+        // 2. write to the claimed index
+
+        // NOTE(panic):
+        // nothing between claiming the index and confirming it may panic,
+        // otherwise the claimed slot would never be confirmed
+        // and all later pushes would spin forever.
+        // `ptr::write` only moves `t` which can not panic.
+
+        // NOTE(unsafe):
+        // the slot is in bounds, since it was claimed below the limit.
+        // it is claimed by this push only and not yet confirmed,
+        // so no reference to it exists and writing through the `Cell` is fine
+        unsafe { self.slot_ptr(idx).write(t) };
+
+        // 3. publish the written slot
+
+        if !self.publish(idx, 1, 1) {
+            // NOTE(unsafe):
+            // the slot was written above and is never confirmed,
+            // so the value is still owned by this push
+            return Err((OomError, unsafe { self.slot_ptr(idx).read() }));
+        }
+
+        Ok(idx)
+    }
+
+    /// Appends an element constructed by `f` after its slot was claimed.
+    ///
+    /// Returns the index of the element.
+    /// If the vector is full, `f` is not called and `OomError` is returned,
+    /// so no value is constructed in vain.
+    ///
+    /// # Panic safety
+    ///
+    /// Other than for `push`, user code runs while the slot is claimed.
+    /// If `f` panics, the slot is given back, if no other push
+    /// claimed a slot behind it in the meantime.
+    /// Otherwise the vector is sealed before the slot just like
+    /// for a short iterator in `extend_exact`, so all later pushes fail
+    /// instead of waiting for the slot forever.
+    /// In that case a value already constructed by `f` is dropped
+    /// if the vector was sealed while `f` was running.
+    ///
+    /// A single panic while a later slot is claimed therefore ends
+    /// the capacity of the vector for all threads:
+    /// every later `push`, `push_with`, `extend_exact` or `reserve` fails
+    /// with `OomError` until the vector is reset by `clear` or `truncate`.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<String>; 1] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// assert_eq!(v.push_with(|| "a".repeat(3)), Ok(0));
+    /// assert_eq!(v.push_with(|| unreachable!()), Err(OomError));
+    /// assert_eq!(v.as_slice(), &["aaa"]);
+    /// ```
+    pub fn push_with<F>(&self, f: F) -> Result<usize, OomError>
+    where
+        F: FnOnce() -> T,
+    {
+        let idx = self.claim(1).ok_or(OomError)?;
+        // NOTE(panic):
+        // the guard gives back or seals the slot if `f` panics
+        let mut block = BlockGuard {
+            vec: self,
+            start: idx,
+            written: 0,
+            claimed: 1,
+        };
+        let t = f();
+        // NOTE(unsafe):
+        // the slot was claimed above and is in bounds
+        unsafe { self.slot_ptr(idx).write(t) };
+        block.written = 1;
+        if !block.finish() {
+            return Err(OomError);
+        }
+        Ok(idx)
+    }
+
+    /// Appends an element without advancing the confirmed length.
+    ///
+    /// On a vector created by `new_seq` this only claims a slot,
+    /// writes the value and publishes its sequence number,
+    /// so it never waits for anything.
+    /// The element can be read by `get` right away,
+    /// but it becomes part of `len` and `as_slice` only after
+    /// the confirmed length was advanced over it
+    /// by `reconcile`, `len` or any other `push`.
+    ///
+    /// A vector created by `new` has no per slot sequence numbers
+    /// to publish to, so this behaves exactly like `push` there.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    /// use std::sync::atomic::AtomicUsize;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut seq = [
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    ///     AtomicUsize::new(0),
+    /// ];
+    /// let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+    ///
+    /// assert_eq!(v.push_unconfirmed(0), Ok(0));
+    /// assert_eq!(v.push_unconfirmed(1), Ok(1));
+    /// assert_eq!(v.get(1), Some(&1));
+    ///
+    /// assert_eq!(v.reconcile(), 2);
+    /// assert_eq!(v.as_slice(), &[0, 1]);
+    /// ```
+    pub fn push_unconfirmed(&self, t: T) -> Result<usize, (OomError, T)> {
+        let seq = match self.storage.seq() {
+            Some(seq) => seq,
+            None => return self.push(t),
+        };
+        let idx = match self.claim(1) {
+            Some(idx) => idx,
+            None => return Err((OomError, t)),
+        };
+        if idx >= self.limit.load(Ordering::Acquire) {
+            // sealed after the claim, see `publish`
+            return Err((OomError, t));
+        }
+        // NOTE(unsafe):
+        // the slot was claimed above and is in bounds
+        unsafe { self.slot_ptr(idx).write(t) };
+        seq[idx].store(idx + 1, Ordering::Release);
+        Ok(idx)
+    }
+
+    /// Advances the confirmed length over all published elements
+    /// and returns the new length.
+    ///
+    /// This is only needed after `push_unconfirmed`,
+    /// all other operations keep the confirmed length up to date.
+    /// Calling `len` has the same effect.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// assert_eq!(v.reconcile(), 1);
+    /// ```
+    pub fn reconcile(&self) -> usize {
+        self.len()
+    }
+
+    /// Appends an element without synchronizing with other pushes.
+    ///
+    /// This behaves like `push`, but claims and confirms the slot
+    /// with plain stores instead of read-modify-writes and never waits,
+    /// which is cheaper for a vector with only one producer.
+    /// Readers on other threads are still fine.
+    ///
+    /// # Safety
+    /// No other push of any kind may run concurrently,
+    /// and all earlier pushes must be confirmed,
+    /// i.e. no range returned by `reserve` may be left uncommitted.
+    /// Otherwise slots would be claimed twice or confirmed unwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 2] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// // only this thread pushes
+    /// unsafe {
+    ///     assert_eq!(v.push_unsync(0), Ok(0));
+    ///     assert_eq!(v.push_unsync(1), Ok(1));
+    ///     assert_eq!(v.push_unsync(2), Err((OomError, 2)));
+    /// }
+    /// assert_eq!(v.as_slice(), &[0, 1]);
+    /// ```
+    pub unsafe fn push_unsync(&self, t: T) -> Result<usize, (OomError, T)> {
+        // NOTE(ordering):
+        // the caller is the only one writing the indices,
+        // so relaxed loads see its own latest stores
+        let idx = self.next_idx.load(Ordering::Relaxed);
+        if idx >= self.limit.load(Ordering::Relaxed) {
+            return Err((OomError, t));
+        }
+        self.next_idx.store(idx + 1, Ordering::Relaxed);
+        // NOTE(unsafe):
+        // the slot is below the limit and claimed by nobody else
+        self.slot_ptr(idx).write(t);
+        // NOTE(ordering):
+        // the release stores publish the slot to readers, like in `publish`
+        if let Some(seq) = self.storage.seq() {
+            seq[idx].store(idx + 1, Ordering::Release);
+        }
+        self.confirmed_len.store(idx + 1, Ordering::Release);
+        Ok(idx)
+    }
+
+    /// Appends all elements of an exactly sized iterator
+    /// as one contiguous block.
+    ///
+    /// Other than pushing the elements one by one,
+    /// this claims all slots for the elements at once
+    /// and confirms them all together.
+    /// Returns the index of the first inserted element.
+    /// If the vector has not enough space left for all elements,
+    /// no element is inserted and `OomError` is returned.
+    ///
+    /// If the iterator yields fewer elements than it reported,
+    /// only those are inserted.
+    /// The unused slots are given back if no other push
+    /// claimed a slot behind them in the meantime.
+    /// Otherwise the vector is sealed after the inserted elements,
+    /// since there is a gap which would never be filled.
+    /// All later pushes will fail then.
+    ///
+    /// The iterator must not push to the same vector,
+    /// unless it was created with `new_seq`.
+    /// Such pushes would wait for the block forever.
+    ///
+    /// # Panic safety
+    ///
+    /// If the iterator panics, the elements taken from it so far
+    /// are confirmed just like for a short iterator before unwinding,
+    /// so readers never see any of the unwritten slots.
+    ///
+    /// If a later slot was claimed in the meantime, this seals the vector,
+    /// so a single panic ends its capacity for all threads:
+    /// every later push fails with `OomError`
+    /// until the vector is reset by `clear` or `truncate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// assert_eq!(v.extend_exact(vec![1, 2]), Ok(1));
+    /// assert_eq!(v.extend_exact(vec![3, 4]), Err(OomError));
+    ///
+    /// assert_eq!(v.as_slice(), &[0, 1, 2]);
+    /// ```
+    pub fn extend_exact<I>(&self, iter: I) -> Result<usize, OomError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let claimed = iter.len();
+        let start = self.claim(claimed).ok_or(OomError)?;
+        if claimed == 0 {
+            return Ok(start);
+        }
+
+        // NOTE(panic):
+        // the iterator may panic while the block is claimed,
+        // the guard then confirms the elements written so far
+        let mut block = BlockGuard {
+            vec: self,
+            start,
+            written: 0,
+            claimed,
+        };
+        // NOTE(order):
+        // zip takes a slot before asking the iterator,
+        // so no more than `claimed` elements are taken
+        for (idx, t) in (start..start + claimed).zip(iter) {
+            // NOTE(unsafe):
+            // the slot was claimed above and is in bounds
+            unsafe { self.slot_ptr(idx).write(t) };
+            block.written += 1;
+        }
+
+        if !block.finish() {
+            return Err(OomError);
+        }
+
+        Ok(start)
+    }
+
+    /// Pushes elements of the iterator until it is exhausted
+    /// or the vector is full and returns how many were pushed.
+    ///
+    /// The iterator is peekable, so an element is only taken from it
+    /// once its slot is claimed.
+    /// The element which did not fit stays in the iterator,
+    /// so pushing can be retried later with the same iterator.
+    /// Each element is pushed on its own, like for `push`.
+    ///
+    /// If the vector was sealed concurrently, e.g. by a short iterator
+    /// in `extend_exact`, the element taken for the last claimed slot
+    /// can never be confirmed and is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// let mut queue = (0..6).peekable();
+    /// assert_eq!(v.try_push_many(&mut queue), 4);
+    /// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    ///
+    /// // the rest is still in the queue
+    /// assert_eq!(queue.collect::<Vec<_>>(), [4, 5]);
+    /// ```
+    pub fn try_push_many<I>(&self, iter: &mut Peekable<I>) -> usize
+    where
+        I: Iterator<Item = T>,
+    {
+        let mut pushed = 0;
+        while iter.peek().is_some() {
+            let idx = match self.claim(1) {
+                Some(idx) => idx,
+                None => break,
+            };
+            // NOTE(panic):
+            // the element was peeked already,
+            // so taking it runs no user code which could panic
+            let t = match iter.next() {
+                Some(t) => t,
+                None => {
+                    self.publish(idx, 0, 1);
+                    break;
+                }
+            };
+            // NOTE(unsafe):
+            // the slot was claimed above and is in bounds
+            unsafe { self.slot_ptr(idx).write(t) };
+            if !self.publish(idx, 1, 1) {
+                // NOTE(unsafe):
+                // the slot is never confirmed, so the value is still ours
+                drop(unsafe { self.slot_ptr(idx).read() });
+                break;
+            }
+            pushed += 1;
+        }
+        pushed
+    }
+
+    /// Pushes all elements of the iterator and returns how many were pushed.
+    ///
+    /// This is the fallible counterpart of `Extend`, built on `try_push_many`.
+    /// If the vector fills up first, the number of pushed elements
+    /// and `OomError` are returned together with the rest of the iterator,
+    /// which still holds the element which did not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// assert_eq!(v.extend_checked(vec![0, 1]).ok(), Some(2));
+    ///
+    /// let (pushed, OomError, rest) = v.extend_checked(vec![2, 3, 4, 5]).unwrap_err();
+    /// assert_eq!(pushed, 2);
+    /// assert_eq!(rest.collect::<Vec<_>>(), [4, 5]);
+    /// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn extend_checked<I>(
+        &self,
+        iter: I,
+    ) -> Result<usize, (usize, OomError, Peekable<I::IntoIter>)>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter().peekable();
+        let pushed = self.try_push_many(&mut iter);
+        if iter.peek().is_none() {
+            Ok(pushed)
+        } else {
+            Err((pushed, OomError, iter))
+        }
+    }
+
+    /// Appends all elements of a slice as one contiguous block
+    /// and returns the indices they were inserted at.
+    ///
+    /// All slots are claimed at once and the elements are copied in bulk,
+    /// so this is much faster than pushing them one by one.
+    /// If the vector has not enough space left for all elements,
+    /// no element is inserted and `OomError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use abao::OomError;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// assert_eq!(v.extend_from_slice(&[1, 2]), Ok(1..3));
+    /// assert_eq!(v.extend_from_slice(&[3, 4]), Err(OomError));
+    ///
+    /// assert_eq!(v.as_slice(), &[0, 1, 2]);
+    /// ```
+    pub fn extend_from_slice(&self, src: &[T]) -> Result<Range<usize>, OomError>
+    where
+        T: Copy,
+    {
+        let start = self.claim(src.len()).ok_or(OomError)?;
+        let end = start + src.len();
+        if src.is_empty() {
+            return Ok(start..end);
+        }
+        // NOTE(unsafe):
+        // the slots were claimed above and are in bounds.
+        // the source is borrowed, so it can not overlap the claimed slots
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), self.slot_ptr(start), src.len());
+        }
+        if !self.publish(start, src.len(), src.len()) {
+            // the copies are never confirmed and need no drop
+            return Err(OomError);
+        }
+        Ok(start..end)
+    }
+
+    /// Appends clones of the confirmed elements of another vector
+    /// as one contiguous block and returns the indices they were inserted at.
+    ///
+    /// Only the elements confirmed in `other` when this is called are copied,
+    /// later pushes to it are not.
+    /// If the vector has not enough space left for all of them,
+    /// no element is inserted and `OomError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut a_buf: [MaybeUninit<String>; 2] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let a = AbaoVec::new(&mut a_buf[..]);
+    /// a.push("a".to_string()).unwrap();
+    /// a.push("b".to_string()).unwrap();
+    ///
+    /// let mut buf: [MaybeUninit<String>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push("x".to_string()).unwrap();
+    ///
+    /// assert_eq!(v.append_from(&a), Ok(1..3));
+    /// assert_eq!(v.as_slice(), &["x", "a", "b"]);
+    /// ```
+    pub fn append_from<R>(&self, other: &AbaoVecInner<T, R>) -> Result<Range<usize>, OomError>
+    where
+        T: Clone,
+        R: Storage<T>,
+    {
+        let src = other.as_slice();
+        let start = self.extend_exact(src.iter().cloned())?;
+        Ok(start..start + src.len())
+    }
+
+    /// Reserves `n` contiguous slots to be written manually.
+    ///
+    /// Returns the range of the reserved indices.
+    /// Nothing is reserved if fewer than `n` slots are left,
+    /// then `OomError` is returned.
+    ///
+    /// The slots can be written in any order through `as_mut_ptr`
+    /// and become part of the vector once `commit` is called with the range.
+    /// This is meant for foreign code or scatter writes,
+    /// which can not go through `extend_exact`.
+    ///
+    /// Without sequence numbers, the confirmed length can not advance
+    /// past a reserved range until it is committed,
+    /// so all pushes after it wait for the commit.
+    /// A reserved range that is never committed blocks them forever.
+    /// Values in a range that is never committed are not dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// let range = v.reserve(3).unwrap();
+    /// assert_eq!(range, 0..3);
+    /// assert!(v.reserve(2).is_err());
+    ///
+    /// let ptr = v.as_mut_ptr();
+    /// for idx in range.clone().rev() {
+    ///     unsafe { ptr.add(idx).write(idx as u32 * 10) };
+    /// }
+    /// assert_eq!(v.len(), 0);
+    ///
+    /// unsafe { v.commit(range) }.unwrap();
+    /// assert_eq!(v.as_slice(), &[0, 10, 20]);
+    /// ```
+    pub fn reserve(&self, n: usize) -> Result<Range<usize>, OomError> {
+        let start = self.claim(n).ok_or(OomError)?;
+        Ok(start..start + n)
+    }
+
+    /// Confirms a range of slots previously returned by `reserve`.
+    ///
+    /// Without sequence numbers, this waits until all ranges before it
+    /// are confirmed, just like `push`.
+    /// Ranges reserved concurrently therefore have to be committed
+    /// from different threads, if they are committed in reverse order.
+    /// With sequence numbers this never waits.
+    ///
+    /// Returns `OomError` if the range can never be confirmed,
+    /// because the vector was sealed before it,
+    /// e.g. by a short iterator in `extend_exact`.
+    /// The written values are still owned by the caller in this case.
+    ///
+    /// # Safety
+    /// `range` must be exactly a range returned by `reserve` on this vector,
+    /// which has not been committed before,
+    /// and all of its slots must be initialized.
+    pub unsafe fn commit(&self, range: Range<usize>) -> Result<(), OomError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let n = range.end - range.start;
+        if !self.publish(range.start, n, n) {
+            return Err(OomError);
+        }
+        Ok(())
+    }
+
+    /// Reserves `n` contiguous slots to be written in place
+    /// and returns a guard exposing them as uninitialized values.
+    ///
+    /// This is the guarded counterpart of `reserve`,
+    /// e.g. for reading from a socket straight into the vector.
+    /// The written prefix of the slots is confirmed by `ReservedSlots::commit`.
+    /// Dropping the guard without committing gives back the slots,
+    /// or seals the vector if later slots were claimed already,
+    /// just like a short iterator in `extend_exact`.
+    /// Committing only a prefix, dropping the guard or panicking
+    /// while it is held then ends the capacity of the vector for all threads:
+    /// every later push fails with `OomError`
+    /// until the vector is reset by `clear` or `truncate`.
+    /// Nothing is reserved if fewer than `n` slots are left,
+    /// then `OomError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 8] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push(0).unwrap();
+    ///
+    /// let mut slots = v.reserve_uninit(4).unwrap();
+    /// assert_eq!(slots.range(), 1..5);
+    /// for (i, slot) in slots.as_uninit_mut()[..2].iter_mut().enumerate() {
+    ///     slot.write(i as u8 + 1);
+    /// }
+    /// assert_eq!(unsafe { slots.commit(2) }, Ok(1..3));
+    ///
+    /// // the unused slots were given back
+    /// assert_eq!(v.push(3), Ok(3));
+    /// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    /// ```
+    #[doc(alias = "try_reserve")]
+    pub fn reserve_uninit(&self, n: usize) -> Result<ReservedSlots<'_, T, S>, OomError> {
+        let start = self.claim(n).ok_or(OomError)?;
+        Ok(ReservedSlots {
+            block: BlockGuard {
+                vec: self,
+                start,
+                written: 0,
+                claimed: n,
+            },
+        })
+    }
+
+    /// Returns a raw pointer to the start of the buffer.
+    ///
+    /// This is meant for writing slots reserved by `reserve`.
+    /// Writing any other slot through this pointer is undefined behaviour,
+    /// as is reading a slot which is not confirmed yet.
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.buf().as_ptr() as *mut T
+    }
+
+    /// Returns the slots which were not claimed yet
+    /// as a slice of uninitialized values.
+    ///
+    /// This needs exclusive access, so no push can claim the slots
+    /// while they are filled, e.g. by reading from a socket into them.
+    /// The filled slots are confirmed afterwards with `set_len`.
+    /// Slots after the vector was sealed are not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 8] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    /// v.push(0).unwrap();
+    ///
+    /// let spare = v.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 7);
+    /// spare[0].write(1);
+    /// spare[1].write(2);
+    /// unsafe { v.set_len(3) };
+    ///
+    /// assert_eq!(v.as_slice(), &[0, 1, 2]);
+    /// ```
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let start = self.claimed_len();
+        let end = self.limit.load(Ordering::Relaxed).max(start);
+        // NOTE(unsafe):
+        // the slots are in bounds and not claimed by any push,
+        // which the exclusive borrow prevents until it ends
+        unsafe {
+            let ptr = self.buf().as_ptr().add(start) as *mut MaybeUninit<T>;
+            core::slice::from_raw_parts_mut(ptr, end - start)
+        }
+    }
+
+    /// Sets the length of the vector, like `Vec::set_len`.
+    ///
+    /// This confirms all slots below `new_len` at once,
+    /// e.g. after filling them through `spare_capacity_mut`.
+    /// Lowering the length forgets the elements after it without dropping.
+    ///
+    /// # Safety
+    /// `new_len` must not reach past the slots
+    /// returned by `spare_capacity_mut`,
+    /// and all slots below `new_len` must be initialized.
+    #[track_caller]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.limit.load(Ordering::Relaxed));
+        self.high_water = self.high_water_mark();
+        if let Some(seq) = self.storage.seq() {
+            // published slots are always claimed,
+            // so only the claimed ones need resetting
+            let claimed = self.next_idx.load(Ordering::Relaxed).min(seq.len());
+            for (idx, s) in seq[..claimed.max(new_len)].iter().enumerate() {
+                s.store(if idx < new_len { idx + 1 } else { 0 }, Ordering::Relaxed);
+            }
+        }
+        self.next_idx.store(new_len, Ordering::Relaxed);
+        self.confirmed_len.store(new_len, Ordering::Relaxed);
+    }
+
+    /// Drops all elements of the vector in place
+    /// and makes the whole buffer available for pushing again.
+    ///
+    /// This needs exclusive access, so no references into the vector
+    /// and no pushes can be outstanding.
+    /// A vector which was sealed is usable again afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<String>; 2] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push("a".to_string()).unwrap();
+    /// v.push("b".to_string()).unwrap();
+    /// assert!(v.push("c".to_string()).is_err());
+    ///
+    /// v.clear();
+    /// assert_eq!(v.len(), 0);
+    /// v.push("c".to_string()).unwrap();
+    /// assert_eq!(v.as_slice(), &["c"]);
+    /// ```
+    #[doc(alias = "reset")]
+    pub fn clear(&mut self) {
+        self.high_water = self.high_water_mark();
+        self.drop_elements(0);
+        if let Some(seq) = self.storage.seq() {
+            for s in seq {
+                s.store(0, Ordering::Relaxed);
+            }
+        }
+        self.next_idx.store(0, Ordering::Relaxed);
+        self.confirmed_len.store(0, Ordering::Relaxed);
+        self.limit.store(self.buf().len(), Ordering::Relaxed);
+    }
+
+    /// Drops the elements from index `len` on in place
+    /// and makes their slots available for pushing again.
+    ///
+    /// Nothing happens if `len` is not below the current length.
+    /// Like `clear`, this needs exclusive access
+    /// and a sealed vector is usable again afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<String>; 3] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let mut v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push("a".to_string()).unwrap();
+    /// v.push("b".to_string()).unwrap();
+    /// v.push("c".to_string()).unwrap();
+    ///
+    /// v.truncate(1);
+    /// assert_eq!(v.as_slice(), &["a"]);
+    /// v.push("d".to_string()).unwrap();
+    /// assert_eq!(v.as_slice(), &["a", "d"]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        self.high_water = self.high_water_mark();
+        self.drop_elements(len);
+        if let Some(seq) = self.storage.seq() {
+            for s in &seq[len..] {
+                s.store(0, Ordering::Relaxed);
+            }
+        }
+        self.next_idx.store(len, Ordering::Relaxed);
+        self.confirmed_len.store(len, Ordering::Relaxed);
+        self.limit.store(self.buf().len(), Ordering::Relaxed);
+    }
+
+    /// Extracts a slice containing the entire vector up to the current length.
+    ///
+    /// This slice does not include elements that are currently being inserted.
+    /// However it contains only fully inserted elements.
+    ///
+    /// The slice borrows the vector immutably.
+    /// Since `push` only needs `&self`, the slice may be held
+    /// while further elements are pushed, which never touches
+    /// the elements already contained in the slice.
+    /// Holding it across any method taking `&mut self` is rejected
+    /// by the borrow checker.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// assert_eq!(v.as_slice(), &[]);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// assert_eq!(v.as_slice(), &[0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.effective_len();
+        // NOTE(unsafe):
+        // the effective length is never out of bounds of the buffer
+        // and all slots below it are initialized and never written again.
+        // `Cell<MaybeUninit<T>>` has the same layout as `T`,
+        // and the pointer is derived from the whole buffer,
+        // so it may be used for all `len` elements
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), len) }
+    }
+
+    /// Returns an iterator over the vector up to the current length.
+    ///
+    /// The length is taken once when creating the iterator,
+    /// so elements pushed afterwards are not yielded by it.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    /// v.push(3).unwrap();
+    ///
+    /// assert_eq!(v.iter().copied().sum::<u32>(), 6);
+    /// ```
+    ///
+    /// Elements pushed after creating the iterator are not observed:
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(1).unwrap();
+    /// let iter = v.iter();
+    /// v.push(2).unwrap();
+    ///
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![&1]);
+    /// assert_eq!(v.len(), 2);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a cursor reading the vector from the start,
+    /// which can be polled again after the vector grew.
+    ///
+    /// See `AbaoCursor`.
+    pub fn cursor(&self) -> AbaoCursor<'_, T, S> {
+        AbaoCursor::new(self)
+    }
+
+    /// Returns an iterator over the elements from index `start`
+    /// up to the current length.
+    ///
+    /// A reader remembering how many elements it consumed
+    /// can continue with the new elements this way.
+    /// The iterator is empty if `start` is not below the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// assert_eq!(v.iter_from(0).collect::<Vec<_>>(), [&1, &2]);
+    /// let consumed = v.len();
+    /// assert_eq!(v.iter_from(consumed).next(), None);
+    /// assert_eq!(v.iter_from(100).next(), None);
+    ///
+    /// v.push(3).unwrap();
+    /// assert_eq!(v.iter_from(consumed).collect::<Vec<_>>(), [&3]);
+    /// ```
+    pub fn iter_from(&self, start: usize) -> core::slice::Iter<'_, T> {
+        let slice = self.as_slice();
+        slice[start.min(slice.len())..].iter()
+    }
+
+    /// Returns an iterator over all overlapping windows of `size` elements,
+    /// like `slice::windows`.
+    ///
+    /// As for `iter`, the windows are taken from one snapshot
+    /// of the vector, elements pushed afterwards are not observed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// for i in 0..4 {
+    ///     v.push(i).unwrap();
+    /// }
+    ///
+    /// let windows = v.windows(2);
+    /// v.push(4).unwrap();
+    /// assert_eq!(windows.collect::<Vec<_>>(), [[0, 1], [1, 2], [2, 3]]);
+    /// ```
+    #[track_caller]
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Returns an iterator over chunks of `size` elements,
+    /// like `slice::chunks`.
+    ///
+    /// The last chunk is shorter if the length is not a multiple of `size`.
+    /// As for `iter`, the chunks are taken from one snapshot
+    /// of the vector, elements pushed afterwards are not observed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// for i in 0..5 {
+    ///     v.push(i).unwrap();
+    /// }
+    ///
+    /// let chunks: Vec<&[u32]> = v.chunks(3).collect();
+    /// assert_eq!(chunks, [&[0, 1, 2][..], &[3, 4][..]]);
+    /// ```
+    #[track_caller]
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Returns `true` if the vector contains an element equal to `x`.
+    ///
+    /// The length is loaded once, so elements pushed during the scan
+    /// are not observed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// assert!(v.contains(&2));
+    /// assert!(!v.contains(&3));
+    ///
+    /// v.push(3).unwrap();
+    /// assert!(v.contains(&3));
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Folds the elements up to the current length into one value.
+    ///
+    /// The length is loaded once, elements pushed during the fold
+    /// are not included.
+    /// Other than a slice, nothing of the snapshot outlives the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// for i in 1..=5 {
+    ///     v.push(i).unwrap();
+    /// }
+    ///
+    /// assert_eq!(v.fold(0, |sum, x| sum + x), 15);
+    /// let even = v.fold(0, |n, x| if x % 2 == 0 { n + 1 } else { n });
+    /// assert_eq!(even, 2);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.as_slice().iter().fold(init, f)
+    }
+
+    /// Maps the elements up to the current length
+    /// into a new vector in the buffer `dst`.
+    ///
+    /// The length is loaded once and `f` is applied to those elements
+    /// in order, the new vector contains all results.
+    /// If `dst` is too small for all elements, `OomError` is returned
+    /// and `dst` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut bytes: [MaybeUninit<u8>; 4] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut bytes[..]);
+    /// v.extend_from_slice(&[1, 2, 255]).unwrap();
+    ///
+    /// let mut words = [const { MaybeUninit::uninit() }; 4];
+    /// let w = v.map_into(&mut words, |&b| u32::from(b) << 8).unwrap();
+    /// assert_eq!(w.as_slice(), &[256, 512, 65280]);
+    ///
+    /// let mut small = [const { MaybeUninit::<u32>::uninit() }; 2];
+    /// assert!(v.map_into(&mut small, |&b| u32::from(b)).is_err());
+    /// ```
+    pub fn map_into<'b, U, F>(
+        &self,
+        dst: &'b mut [MaybeUninit<U>],
+        f: F,
+    ) -> Result<AbaoVec<'b, U>, OomError>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let src = self.as_slice();
+        if dst.len() < src.len() {
+            return Err(OomError);
+        }
+        let mapped = AbaoVec::new(dst);
+        mapped.extend_exact(src.iter().map(f))?;
+        Ok(mapped)
+    }
+
+    /// Returns the index of the first element matching `pred`.
+    ///
+    /// The length is loaded once, so elements pushed during the scan
+    /// are not observed.
+    /// Since elements are never removed or moved,
+    /// the returned index keeps pointing to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(1).unwrap();
+    /// v.push(4).unwrap();
+    ///
+    /// assert_eq!(v.position(|&x| x > 2), Some(1));
+    /// assert_eq!(v.position(|&x| x > 4), None);
+    ///
+    /// v.push(9).unwrap();
+    /// assert_eq!(v.position(|&x| x > 4), Some(2));
+    /// assert_eq!(v.position(|&x| x > 2), Some(1));
+    /// ```
+    pub fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().iter().position(pred)
+    }
+
+    /// Binary searches the sorted vector for `x`.
+    ///
+    /// Returns the index of a matching element as `Ok`,
+    /// or the index where `x` could be inserted to keep the order as `Err`.
+    /// The search runs over one snapshot of the length, like `as_slice`.
+    /// Since elements are never moved, a found index stays valid for `get`.
+    /// The insertion point however may be taken by a concurrent push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u64>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// assert_eq!(v.binary_search(&10), Err(0));
+    ///
+    /// for t in [10, 20, 30, 40] {
+    ///     v.push(t).unwrap();
+    /// }
+    ///
+    /// assert_eq!(v.binary_search(&30), Ok(2));
+    /// assert_eq!(v.binary_search(&25), Err(2));
+    /// assert_eq!(v.binary_search(&50), Err(4));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary searches the sorted vector with a comparator function.
+    ///
+    /// See `binary_search`.
+    /// `f` returns the order of the given element
+    /// relative to the searched one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<(u64, &str)>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push((10, "a")).unwrap();
+    /// v.push((20, "b")).unwrap();
+    ///
+    /// assert_eq!(v.binary_search_by(|(t, _)| t.cmp(&20)), Ok(1));
+    /// assert_eq!(v.binary_search_by(|(t, _)| t.cmp(&15)), Err(1));
+    /// ```
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Returns a raw pointer to the start of the buffer.
+    ///
+    /// Only the first `len()` elements behind the pointer are guaranteed
+    /// to be initialized, and only those may be read.
+    /// The pointer stays valid until the vector is dropped
+    /// or mutably borrowed.
+    pub fn as_ptr(&self) -> *const T {
+        self.buf().as_ptr() as *const T
+    }
+
+    /// Returns a raw pointer to the start of the buffer
+    /// and the current length, e.g. for handing the elements to C.
+    ///
+    /// All elements up to the returned length are initialized.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [const { MaybeUninit::uninit() }; 8];
+    /// let v = AbaoVec::from_array(&mut buf);
+    /// v.push(1_u32).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// let (ptr, len) = v.as_raw_parts();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(unsafe { *ptr.add(1) }, 2);
+    /// ```
+    pub fn as_raw_parts(&self) -> (*const T, usize) {
+        (self.as_ptr(), self.len())
+    }
+
+    /// Returns the raw pointer range of the vector up to the current length.
+    ///
+    /// The range is taken from a single load of the length,
+    /// so all elements from `start` up to but excluding `end`
+    /// are fully inserted and stay valid until the vector is dropped
+    /// or mutably borrowed.
+    /// For an empty vector `start` and `end` are equal.
+    ///
+    /// This is meant for foreign code walking the buffer directly.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// let range = v.as_ptr_range();
+    /// assert_eq!(range.start, range.end);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    ///
+    /// let range = v.as_ptr_range();
+    /// assert_eq!(unsafe { range.end.offset_from(range.start) }, 2);
+    /// ```
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        self.as_slice().as_ptr_range()
+    }
+
+    /// Extracts a slice of the elements in `range`
+    /// without loading the length or checking bounds.
+    ///
+    /// This is meant for hot loops which already took
+    /// a snapshot of the length, e.g. by calling `len`.
+    ///
+    /// # Safety
+    /// `range.start <= range.end` must hold and `range.end` must not be
+    /// greater than a length previously returned by `len`
+    /// or `as_slice().len()` of this vector.
+    /// Since the length of the vector never shrinks
+    /// while it is borrowed immutably, such elements stay valid.
+    /// Any other range can cause creating a reference
+    /// to uninitialized memory or memory outside of the underlaying buffer.
+    /// This is generally undefined behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// v.push(0).unwrap();
+    /// v.push(1).unwrap();
+    /// v.push(2).unwrap();
+    ///
+    /// let len = v.len();
+    /// // the range ends at a length returned by `len`
+    /// assert_eq!(unsafe { v.slice_unchecked(1..len) }, &[1, 2]);
+    /// ```
+    pub unsafe fn slice_unchecked(&self, range: Range<usize>) -> &[T] {
+        // NOTE(unsafe):
+        // only safe when the range is within the initialized elements
+        let ptr = self.buf().as_ptr().add(range.start).cast::<T>();
+        core::slice::from_raw_parts(ptr, range.end - range.start)
+    }
+
+    /// Returns a parallel iterator over `chunk_size` elements of the vector at a time.
+    ///
+    /// The chunks are taken from a single snapshot of the vector
+    /// as returned by `as_slice`, so elements which are inserted
+    /// after calling this method are not part of any chunk.
+    /// The last chunk may be shorter than `chunk_size`.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use abao::AbaoVec;
+    /// use rayon::prelude::*;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<u32>; 128] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    ///
+    /// for i in 0..10 {
+    ///     v.push(i).unwrap();
+    /// }
+    ///
+    /// let sums: Vec<u32> = v.par_chunks(4).map(|c| c.iter().sum()).collect();
+    /// assert_eq!(sums, vec![6, 22, 17]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_chunks(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, T>
+    where
+        T: Sync,
+    {
+        use rayon::slice::ParallelSlice;
+        self.as_slice().par_chunks(chunk_size)
+    }
+}
+
+impl<T, S: Storage<T>> AbaoVecInner<T, S> {
+    /// The slots of the backing buffer.
+    fn buf(&self) -> &[Cell<MaybeUninit<T>>] {
+        self.storage.slots()
+    }
+
+    /// The confirmed length, saturated at the capacity of the buffer.
+    ///
+    /// All readers go through this, so they stay in bounds of the buffer
+    /// even if the confirmed length was corrupted somehow.
+    fn effective_len(&self) -> usize {
+        let len = match self.storage.seq() {
+            // NOTE(ordering):
+            // the acquire load synchronizes with the confirming push,
+            // so the elements below the loaded length are visible.
+            // checked by the `len_implies_get` loom models.
+            None => self.confirmed_len.load(Ordering::Acquire),
+            Some(seq) => self.advance(seq),
+        };
+        // NOTE(index):
+        // the confirmed length should never exceed the buffer,
+        // saturating it keeps all readers in bounds anyway
+        len.min(self.buf().len())
+    }
+
+    /// Overwrite the next index, e.g. to simulate claimed slots.
+    #[cfg(test)]
+    fn set_next_idx(&self, idx: usize) {
+        self.next_idx.store(idx, Ordering::Relaxed);
+    }
+
+    /// Overwrite the confirmed length, e.g. to simulate a corrupted state.
+    #[cfg(test)]
+    fn set_confirmed_len(&self, len: usize) {
+        self.confirmed_len.store(len, Ordering::Relaxed);
+    }
+
+    /// Pointer to the value in the slot at `idx`.
+    ///
+    /// The pointer is derived from the whole buffer,
+    /// so it may also be used for the slots after `idx`,
+    /// e.g. to copy a whole block.
+    ///
+    /// # Safety
+    /// `idx` must be in bounds of the buffer.
+    #[inline]
+    unsafe fn slot_ptr(&self, idx: usize) -> *mut T {
+        self.as_mut_ptr().add(idx)
+    }
+
+    /// Claim `n` contiguous slots and return the index of the first one.
+    ///
+    /// Nothing is claimed if fewer than `n` slots are left.
+    fn claim(&self, n: usize) -> Option<usize> {
+        let limit = self.limit.load(Ordering::Acquire);
+        // NOTE(ordering):
+        // the claim only has to be unique, which every read-modify-write
+        // of the next index is, since they are totally ordered.
+        // the claimed slots are private to the claiming thread
+        // until they are published, which synchronizes on its own.
+        self.next_idx
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |idx| {
+                idx.checked_add(n).filter(|&end| end <= limit)
+            })
+            .ok()
+    }
+
+    /// Claim up to `n` contiguous slots, as many as are left.
+    ///
+    /// Returns the index of the first one and the number of claimed slots,
+    /// which is 0 if the vector is full.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    fn claim_up_to(&self, n: usize) -> (usize, usize) {
+        let limit = self.limit.load(Ordering::Acquire);
+        let claimed = |idx: usize| n.min(limit.saturating_sub(idx));
+        // NOTE(ordering):
+        // see `claim`
+        match self
+            .next_idx
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |idx| {
+                Some(idx + claimed(idx))
+            }) {
+            Ok(idx) | Err(idx) => (idx, claimed(idx)),
+        }
+    }
+
+    /// Publish the first `written` slots of the block of `claimed` slots
+    /// starting at `start`, which all have to be initialized.
+    ///
+    /// If not all slots of the block have been written,
+    /// the rest of the block is given back, if nothing was claimed after it,
+    /// or the vector is sealed after the written slots.
+    ///
+    /// Returns `false` if the slots can never be confirmed,
+    /// because the vector was sealed before them.
+    /// The written values are still owned by the caller in this case.
+    fn publish(&self, start: usize, written: usize, claimed: usize) -> bool {
+        let end = start + written;
+        // NOTE(ordering):
+        // giving back the unwritten slots is just another claim,
+        // the given back slots have never been written
+        let abandoned = written < claimed
+            && self
+                .next_idx
+                .compare_exchange(start + claimed, end, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err();
+
+        if written == 0 && !abandoned {
+            // NOTE(progress):
+            // the whole block was given back and nothing has to be published.
+            // waiting for the confirmed length to reach `start` is wrong here,
+            // another push may already have claimed and confirmed the slot
+            // at `start`, after which the confirmed length never equals it
+            return true;
+        }
+
+        if let Some(seq) = self.storage.seq() {
+            if start >= self.limit.load(Ordering::Acquire) {
+                // an earlier block was abandoned,
+                // so the confirmed length will never reach this block
+                return false;
+            }
+            // NOTE(race):
+            // a block abandoned after the check above still seals
+            // the vector before the published slots, which then stay
+            // readable by `get` but never become part of the length
+
+            // publish the slots and help advancing the confirmed length.
+            // this never waits for other pushes.
+            for (idx, s) in (start..end).zip(&seq[start..end]) {
+                s.store(idx + 1, Ordering::Release);
+            }
+            if abandoned {
+                self.limit.fetch_min(end, Ordering::AcqRel);
+            }
+            self.advance(seq);
+            return true;
+        }
+
+        // increase the confirmed length to be the end of the block,
+        // but only if all previous writes have finished.
+        // this ensures that read calls can only access
+        // completely initialized memory.
+
+        // NOTE(spinlock):
+        // this waits for all earlier pushes to finish their writes.
+        // the backoff keeps waiting threads from hammering the
+        // confirmed length and lets the threads they wait for run,
+        // which matters once there are more pushers than cores.
+
+        // NOTE(ordering):
+        // the release on success publishes the written slots
+        // to readers loading the confirmed length with acquire.
+        // the slots of earlier blocks stay visible to them as well,
+        // since every later successful exchange continues
+        // the release sequence of the earlier ones.
+        // a failed exchange publishes nothing, so it is relaxed.
+        let mut backoff = Backoff::new();
+        while self
+            .confirmed_len
+            .compare_exchange(start, end, Ordering::Release, Ordering::Relaxed)
+            .is_err()
+        {
+            if start >= self.limit.load(Ordering::Acquire) {
+                // an earlier block was abandoned,
+                // so the confirmed length will never reach this block
+                return false;
+            }
+            backoff.snooze()
+        }
+
+        if abandoned {
+            self.limit.fetch_min(end, Ordering::AcqRel);
+        }
+        true
+    }
+
+    /// Drop all elements of the vector at and after index `from` in place.
+    fn drop_elements(&mut self, from: usize) {
+        if !core::mem::needs_drop::<T>() {
+            // this also skips walking a huge buffer of zero sized elements
+            return;
+        }
+        let len = self.len();
+        // with sequence numbers there may be published elements after a gap
+        let end = if self.storage.seq().is_some() {
+            self.buf().len()
+        } else {
+            len
+        };
+        for (idx, cell) in self.buf()[..end].iter().enumerate().skip(from) {
+            if idx >= len && !self.is_published(idx) {
+                continue;
+            }
+            // NOTE(unsafe):
+            unsafe {
                 let cell_ptr = cell.as_ptr();
                 let ptr: *mut T = (&mut *cell_ptr).as_mut_ptr();
-                std::ptr::drop_in_place(ptr);
+                core::ptr::drop_in_place(ptr);
+            }
+        }
+    }
+
+    /// Takes the storage out of the vector without dropping the elements
+    /// of the vector, which are then at the start of the storage.
+    /// Returns the storage and the length of the vector.
+    pub(crate) fn into_storage(mut self) -> (S, usize) {
+        let len = self.len();
+        // published elements after a gap are not part of the vector
+        self.drop_elements(len);
+        let this = core::mem::ManuallyDrop::new(self);
+        // NOTE(unsafe):
+        // the vector is never dropped, so the storage is moved out only once
+        let storage = unsafe { core::ptr::read(&this.storage) };
+        (storage, len)
+    }
+
+    /// Check whether the slot at `idx` has been published
+    /// through its sequence number.
+    fn is_published(&self, idx: usize) -> bool {
+        match self.storage.seq() {
+            Some(seq) => seq
+                .get(idx)
+                .is_some_and(|s| s.load(Ordering::Acquire) == idx + 1),
+            None => false,
+        }
+    }
+
+    /// Advance the confirmed length over all published slots
+    /// and return the new confirmed length.
+    fn advance(&self, seq: &[AtomicUsize]) -> usize {
+        let mut len = self.confirmed_len.load(Ordering::Acquire);
+        while len < seq.len() && seq[len].load(Ordering::Acquire) == len + 1 {
+            // NOTE(ordering):
+            // the acquire load of the sequence number synchronizes with
+            // the pushing thread, the release store of the confirmed length
+            // passes that on to readers of the length.
+            match self.confirmed_len.compare_exchange(
+                len,
+                len + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => len += 1,
+                Err(current) => len = current,
+            }
+        }
+        len
+    }
+}
+
+/// A claimed block of slots which is being written.
+///
+/// Publishes the written slots when dropped,
+/// so a panic while writing the block never leaves it unconfirmed.
+/// Publishing waits for the blocks before it to be confirmed,
+/// unless nothing was written and all slots could be given back.
+/// Then nothing is published and the guard does not wait,
+/// since the given back slots may already be confirmed by another push.
+struct BlockGuard<'v, T, S: Storage<T>> {
+    vec: &'v AbaoVecInner<T, S>,
+    start: usize,
+    written: usize,
+    claimed: usize,
+}
+
+impl<T, S: Storage<T>> BlockGuard<'_, T, S> {
+    /// Publish the written slots.
+    ///
+    /// Returns `false` if they can never be confirmed,
+    /// in which case the written values are dropped.
+    fn publish(&self) -> bool {
+        if self.vec.publish(self.start, self.written, self.claimed) {
+            return true;
+        }
+        for idx in self.start..self.start + self.written {
+            // NOTE(unsafe):
+            // the slots were written and are never confirmed
+            unsafe { drop(self.vec.slot_ptr(idx).read()) };
+        }
+        false
+    }
+
+    /// Publish the written slots without unwinding.
+    fn finish(self) -> bool {
+        // NOTE(panic):
+        // the guard is not dropped afterwards,
+        // even if dropping a value panics
+        core::mem::ManuallyDrop::new(self).publish()
+    }
+}
+
+impl<T, S: Storage<T>> Drop for BlockGuard<'_, T, S> {
+    fn drop(&mut self) {
+        self.publish();
+    }
+}
+
+/// Contiguous slots reserved by `reserve_uninit`.
+///
+/// The slots are private to the guard until they are committed.
+/// Dropping the guard without committing confirms none of them.
+pub struct ReservedSlots<'v, T, S: Storage<T>> {
+    block: BlockGuard<'v, T, S>,
+}
+
+impl<T, S: Storage<T>> ReservedSlots<'_, T, S> {
+    /// The indices of the reserved slots.
+    pub fn range(&self) -> Range<usize> {
+        self.block.start..self.block.start + self.block.claimed
+    }
+
+    /// The reserved slots as uninitialized values.
+    pub fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // NOTE(unsafe):
+        // the slots are claimed by the guard and in bounds,
+        // so nobody else accesses them until they are committed
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.block.vec.slot_ptr(self.block.start) as *mut MaybeUninit<T>,
+                self.block.claimed,
+            )
+        }
+    }
+
+    /// Confirms the first `count` reserved slots
+    /// and returns their indices.
+    ///
+    /// The rest of the slots are handled like dropping the guard.
+    /// Without sequence numbers, this waits until all slots before
+    /// the reserved ones are confirmed, just like `push`.
+    /// Committing nothing never waits if all slots could be given back.
+    ///
+    /// Returns `OomError` if the slots can never be confirmed,
+    /// because the vector was sealed before them.
+    /// The written values are dropped in this case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the number of reserved slots.
+    ///
+    /// # Safety
+    /// The first `count` slots must be initialized.
+    #[track_caller]
+    pub unsafe fn commit(mut self, count: usize) -> Result<Range<usize>, OomError> {
+        assert!(
+            count <= self.block.claimed,
+            "commit count {} exceeds the {} reserved slots",
+            count,
+            self.block.claimed
+        );
+        self.block.written = count;
+        let start = self.block.start;
+        if !self.block.finish() {
+            return Err(OomError);
+        }
+        Ok(start..start + count)
+    }
+}
+
+impl<T, S: Storage<T>> Drop for AbaoVecInner<T, S> {
+    fn drop(&mut self) {
+        self.drop_elements(0);
+    }
+}
+
+// NOTE(unsafe):
+// the slots are only written through the atomic index protocol,
+// never through `Cell::set`, so sharing the `Cell`s is fine.
+// moving the vector moves its elements, which requires `T: Send`,
+// and its storage, which requires `S: Send`.
+unsafe impl<T, S: Storage<T>> Send for AbaoVecInner<T, S>
+where
+    T: Send,
+    S: Send,
+{
+}
+// NOTE(unsafe):
+// a shared vector hands out `&T` to all threads, which requires `T: Sync`.
+// `push` through a shared vector moves the value to the thread
+// which finally drops the vector, which requires `T: Send`.
+// all threads access the storage, which requires `S: Sync`.
+unsafe impl<T, S: Storage<T>> Sync for AbaoVecInner<T, S>
+where
+    T: Send + Sync,
+    S: Sync,
+{
+}
+
+/// Dereferences to the vector up to the current length,
+/// which makes all methods of immutable slices available.
+///
+/// This is the same as `as_slice`, so the slice only contains
+/// the elements confirmed at the time of the call.
+/// Holding the slice while other threads push is sound,
+/// since pushes only write to slots after the current length.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(v.first(), Some(&0));
+/// assert_eq!(v.last(), Some(&2));
+/// assert!(v.contains(&1));
+/// ```
+impl<T, S: Storage<T>> Deref for AbaoVecInner<T, S> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+/// Borrows the elements up to the current length, like `as_slice`.
+///
+/// The length is loaded when borrowing,
+/// later pushes are not part of the returned slice.
+impl<T, S: Storage<T>> AsRef<[T]> for AbaoVecInner<T, S> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+/// Borrows the elements up to the current length, like `as_slice`.
+///
+/// The length is loaded when borrowing,
+/// later pushes are not part of the returned slice.
+impl<T, S: Storage<T>> Borrow<[T]> for AbaoVecInner<T, S> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+/// Panicking access to the element at an index.
+///
+/// # Panics
+///
+/// Panics if `get` returns `None` for the index.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+///
+/// assert_eq!(v[1], 1);
+/// ```
+///
+/// ```should_panic
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+///
+/// // index out of bounds: the len is 1 but the index is 1
+/// let _ = v[1];
+/// ```
+impl<T, S: Storage<T>> Index<usize> for AbaoVecInner<T, S> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, idx: usize) -> &T {
+        match self.get(idx) {
+            Some(t) => t,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                idx
+            ),
+        }
+    }
+}
+
+/// Slicing the vector up to the current length.
+///
+/// # Panics
+///
+/// Panics if `get_range` returns `None` for the range.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(&v[1..3], &[1, 2]);
+/// ```
+impl<T, S: Storage<T>> Index<Range<usize>> for AbaoVecInner<T, S> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, range: Range<usize>) -> &[T] {
+        match self.get_range(range.clone()) {
+            Some(slice) => slice,
+            None => panic!(
+                "range out of bounds: the len is {} but the range is {:?}",
+                self.len(),
+                range
+            ),
+        }
+    }
+}
+
+/// Slicing the vector from the start up to an index.
+///
+/// # Panics
+///
+/// Panics if the end is greater than the current length.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(&v[..2], &[0, 1]);
+/// ```
+impl<T, S: Storage<T>> Index<RangeTo<usize>> for AbaoVecInner<T, S> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, range: RangeTo<usize>) -> &[T] {
+        match self.get_range(0..range.end) {
+            Some(slice) => slice,
+            None => panic!(
+                "range out of bounds: the len is {} but the range is {:?}",
+                self.len(),
+                range
+            ),
+        }
+    }
+}
+
+/// Slicing the vector from an index up to the current length.
+///
+/// # Panics
+///
+/// Panics if the start is greater than the current length.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(&v[1..], &[1, 2]);
+/// assert_eq!(&v[3..], &[]);
+/// ```
+impl<T, S: Storage<T>> Index<RangeFrom<usize>> for AbaoVecInner<T, S> {
+    type Output = [T];
+
+    #[track_caller]
+    fn index(&self, range: RangeFrom<usize>) -> &[T] {
+        let slice = self.as_slice();
+        match slice.get(range.start..) {
+            Some(slice) => slice,
+            None => panic!(
+                "range out of bounds: the len is {} but the range is {:?}",
+                slice.len(),
+                range
+            ),
+        }
+    }
+}
+
+/// The whole vector up to the current length, same as `as_slice`.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+///
+/// assert_eq!(&v[..], &[0]);
+/// ```
+impl<T, S: Storage<T>> Index<RangeFull> for AbaoVecInner<T, S> {
+    type Output = [T];
+
+    fn index(&self, _: RangeFull) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'b, T, S: Storage<T>> IntoIterator for &'b AbaoVecInner<T, S> {
+    type Item = &'b T;
+    type IntoIter = core::slice::Iter<'b, T>;
+
+    fn into_iter(self) -> core::slice::Iter<'b, T> {
+        self.iter()
+    }
+}
+
+/// Pushes all elements of the iterator.
+///
+/// # Panics
+///
+/// Panics if the vector fills up before the iterator is exhausted,
+/// the elements pushed until then stay in the vector.
+/// Use `extend_checked` to get the rest of the iterator back instead.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 4] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let mut v = AbaoVec::new(&mut buf[..]);
+///
+/// v.extend(0..2);
+/// (&v).extend(vec![2, 3]);
+///
+/// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+/// ```
+impl<T, S: Storage<T>> Extend<T> for &AbaoVecInner<T, S> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.extend_checked(iter).is_err() {
+            panic!(
+                "capacity exceeded in extend: the capacity is {}",
+                self.capacity()
+            );
+        }
+    }
+}
+
+impl<T, S: Storage<T>> Extend<T> for AbaoVecInner<T, S> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        (&*self).extend(iter)
+    }
+}
+
+/// Formats the vector like a slice.
+///
+/// Only the elements up to the current length are printed,
+/// elements which are currently being inserted are left out.
+/// The alternate form `{:#?}` pretty-prints the same list.
+/// Use [`capacity`](AbaoVecInner::capacity) and [`snapshot`](AbaoVecInner::snapshot)
+/// to also see the capacity and the slots which are claimed but not yet confirmed.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.push(0).unwrap();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+///
+/// assert_eq!(format!("{:?}", v), "[0, 1, 2]");
+/// ```
+impl<T, S: Storage<T>> fmt::Debug for AbaoVecInner<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.as_slice().iter()).finish()
+    }
+}
+
+/// Compares the elements up to the current length of both vectors.
+///
+/// Each length is loaded once, elements which are currently being inserted
+/// are left out on both sides.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut a_buf: [MaybeUninit<u8>; 4] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let mut b_buf: [MaybeUninit<u8>; 128] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let a = AbaoVec::new(&mut a_buf[..]);
+/// let b = AbaoVec::new(&mut b_buf[..]);
+///
+/// for i in 0..3 {
+///     a.push(i).unwrap();
+///     b.push(i).unwrap();
+/// }
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a, [0, 1, 2]);
+/// b.push(3).unwrap();
+/// assert_ne!(a, b);
+/// ```
+impl<T, U, S, R> PartialEq<AbaoVecInner<U, R>> for AbaoVecInner<T, S>
+where
+    T: PartialEq<U>,
+    S: Storage<T>,
+    R: Storage<U>,
+{
+    fn eq(&self, other: &AbaoVecInner<U, R>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, S: Storage<T>> Eq for AbaoVecInner<T, S> {}
+
+impl<T, U, S: Storage<T>> PartialEq<[U]> for AbaoVecInner<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, S: Storage<T>> PartialEq<&[U]> for AbaoVecInner<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T, U, S: Storage<T>, const N: usize> PartialEq<[U; N]> for AbaoVecInner<T, S>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+/// Compares the elements up to the current length of both vectors
+/// lexicographically, like slices.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut a_buf: [MaybeUninit<u8>; 4] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let mut b_buf: [MaybeUninit<u8>; 4] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let a = AbaoVec::new(&mut a_buf[..]);
+/// let b = AbaoVec::new(&mut b_buf[..]);
+///
+/// a.extend_from_slice(&[0, 1]).unwrap();
+/// b.extend_from_slice(&[0, 2]).unwrap();
+///
+/// assert!(a < b);
+/// ```
+impl<T, S, R> PartialOrd<AbaoVecInner<T, R>> for AbaoVecInner<T, S>
+where
+    T: PartialOrd,
+    S: Storage<T>,
+    R: Storage<T>,
+{
+    fn partial_cmp(&self, other: &AbaoVecInner<T, R>) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, S: Storage<T>> Ord for AbaoVecInner<T, S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+/// Hashes the elements up to the current length like a slice,
+/// so the hash is the same as for `as_slice`.
+impl<T: Hash, S: Storage<T>> Hash for AbaoVecInner<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Copy, S: Storage<T>> AbaoVecInner<T, S> {
+    /// Appends as many elements of `src` as fit into the vector
+    /// as one contiguous block and returns how many were appended.
+    fn extend_from_slice_partial(&self, src: &[T]) -> usize {
+        let (start, n) = self.claim_up_to(src.len());
+        if n == 0 {
+            return 0;
+        }
+        // NOTE(unsafe):
+        // the slots were claimed above and are in bounds
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), self.slot_ptr(start), n);
+        }
+        if self.publish(start, n, n) {
+            n
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, S: Storage<T>> AbaoVecInner<T, S> {
+    /// Moves the elements up to the current length into a `Vec`.
+    ///
+    /// The elements are moved into a new allocation,
+    /// the buffer of the vector is uninitialized afterwards.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use abao::AbaoVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf: [MaybeUninit<String>; 8] = unsafe {
+    ///     MaybeUninit::uninit().assume_init()
+    /// };
+    /// let v = AbaoVec::new(&mut buf[..]);
+    /// v.push(String::from("a")).unwrap();
+    /// v.push(String::from("b")).unwrap();
+    ///
+    /// assert_eq!(v.into_vec(), ["a", "b"]);
+    /// ```
+    pub fn into_vec(self) -> alloc::vec::Vec<T> {
+        let (storage, len) = self.into_storage();
+        let mut vec = alloc::vec::Vec::with_capacity(len);
+        // NOTE(unsafe):
+        // the first `len` slots are initialized and owned by nobody
+        // but the storage, which never drops its slots.
+        // they are moved into the vector and not read again
+        unsafe {
+            let src = storage.slots().as_ptr().cast::<T>();
+            core::ptr::copy_nonoverlapping(src, vec.as_mut_ptr(), len);
+            vec.set_len(len);
+        }
+        vec
+    }
+}
+
+/// Writes bytes to the end of the vector.
+///
+/// A write appends as many bytes as fit into the vector as one block,
+/// so it may be short when the vector fills up.
+/// A write to a full vector returns `Ok(0)`.
+/// Flushing does nothing.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::io::Write;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 8] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let mut v = AbaoVec::new(&mut buf[..]);
+///
+/// write!(v, "{}-{}", 1, 2).unwrap();
+/// assert_eq!(v.as_slice(), b"1-2");
+///
+/// assert_eq!(v.write(b"abcdefgh").unwrap(), 5);
+/// assert_eq!(v.write(b"i").unwrap(), 0);
+/// assert_eq!(v.as_slice(), b"1-2abcde");
+/// ```
+#[cfg(feature = "std")]
+impl<S: Storage<u8>> std::io::Write for AbaoVecInner<u8, S> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.extend_from_slice_partial(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes bytes to the end of a shared vector,
+/// so several threads can write to it at once.
+///
+/// Each single write is appended as one contiguous block.
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+impl<S: Storage<u8>> std::io::Write for &AbaoVecInner<u8, S> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.extend_from_slice_partial(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes formatted text to the end of the vector.
+///
+/// Each string piece is appended as one block with `extend_from_slice`,
+/// so the vector only ever contains whole UTF-8 characters.
+/// If a piece does not fit, `fmt::Error` is returned
+/// and the pieces written before it stay in the vector.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::fmt::Write;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 16] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let mut v = AbaoVec::new(&mut buf[..]);
+///
+/// write!(v, "{} items", 42).unwrap();
+/// assert_eq!(core::str::from_utf8(v.as_slice()), Ok("42 items"));
+/// ```
+impl<S: Storage<u8>> fmt::Write for AbaoVecInner<u8, S> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend_from_slice(s.as_bytes())
+            .map(|_| ())
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// Formats the bytes up to the current length as UTF-8 text.
+///
+/// Invalid sequences are replaced with `U+FFFD`,
+/// like `String::from_utf8_lossy` does, so this never fails.
+///
+/// # Examples
+///
+/// ```
+/// use abao::AbaoVec;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf: [MaybeUninit<u8>; 16] = unsafe {
+///     MaybeUninit::uninit().assume_init()
+/// };
+/// let v = AbaoVec::new(&mut buf[..]);
+///
+/// v.extend_from_slice(b"abao").unwrap();
+/// assert_eq!(format!("{}", v), "abao");
+///
+/// v.extend_from_slice(b" \xff!").unwrap();
+/// assert_eq!(format!("{}", v), "abao \u{FFFD}!");
+/// ```
+impl<S: Storage<u8>> fmt::Display for AbaoVecInner<u8, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.as_slice().utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_str("\u{FFFD}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the vector as a sequence of the elements up to the current length.
+///
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<T, S: Storage<T>> serde::Serialize for AbaoVecInner<T, S>
+where
+    T: serde::Serialize,
+{
+    fn serialize<Se: serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        use serde::ser::SerializeSeq;
+        // take the length once, so the number of elements matches it
+        let slice = self.as_slice();
+        let mut seq = serializer.serialize_seq(Some(slice.len()))?;
+        for t in slice {
+            seq.serialize_element(t)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::AbaoVec;
+    use crate::OomError;
+    use std::mem::MaybeUninit;
+
+    // regular behavior to be run by miri
+    #[test]
+    fn regular() {
+        let mut buf: [MaybeUninit<u8>; 128] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.as_slice(), &[]);
+        v.push(0).unwrap();
+        assert_eq!(v.len(), 1);
+        v.push(1).unwrap();
+        assert_eq!(v.len(), 2);
+        v.push(2).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.get(0), Some(&0));
+        assert_eq!(v.get(1), Some(&1));
+        assert_eq!(v.get(2), Some(&2));
+        assert_eq!(v.as_slice(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn dropable() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering;
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug)]
+        struct X;
+        impl X {
+            fn new() -> X {
+                COUNT.fetch_add(1, Ordering::Relaxed);
+                X
+            }
+        }
+        impl Drop for X {
+            fn drop(&mut self) {
+                COUNT.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+        let mut buf: [MaybeUninit<X>; 128] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.len(), 0);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+        v.push(X::new()).unwrap();
+        assert_eq!(v.len(), 1);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+        v.push(X::new()).unwrap();
+        assert_eq!(v.len(), 2);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 2);
+        v.push(X::new()).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+        drop(v);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    }
+
+    /// Counts its drops in the slot of its id.
+    #[derive(Debug)]
+    struct Counted<'c> {
+        id: usize,
+        drops: &'c [std::sync::atomic::AtomicUsize],
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops[self.id].fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    fn drop_counts(drops: &[std::sync::atomic::AtomicUsize]) -> Vec<usize> {
+        drops
+            .iter()
+            .map(|d| d.load(std::sync::atomic::Ordering::Relaxed))
+            .collect()
+    }
+
+    #[test]
+    fn drop_failed_pushes() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..6).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let mut rejected = Vec::new();
+        for id in 0..6 {
+            match v.push(Counted { id, drops }) {
+                Ok(idx) => assert_eq!(idx, id),
+                Err((OomError, t)) => rejected.push(t),
+            }
+        }
+        assert_eq!(v.len(), 4);
+        assert_eq!(rejected.iter().map(|t| t.id).collect::<Vec<_>>(), [4, 5]);
+        // nothing is dropped while the vector is alive
+        assert_eq!(drop_counts(drops), [0; 6]);
+
+        drop(v);
+        assert_eq!(drop_counts(drops), [1, 1, 1, 1, 0, 0]);
+        // the rejected values belong to the caller
+        drop(rejected);
+        assert_eq!(drop_counts(drops), [1; 6]);
+    }
+
+    #[test]
+    fn drop_concurrent_failed_pushes() {
+        use std::sync::atomic::AtomicUsize;
+        use std::thread;
+        let threads = 4;
+        let per_thread = 64;
+        let drops: Vec<AtomicUsize> = (0..threads * per_thread)
+            .map(|_| AtomicUsize::new(0))
+            .collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 100] =
+            unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let rejected: usize = thread::scope(|s| {
+            let v = &v;
+            let handles: Vec<_> = (0..threads)
+                .map(|t| {
+                    s.spawn(move || {
+                        (t * per_thread..(t + 1) * per_thread)
+                            .filter(|&id| v.push(Counted { id, drops }).is_err())
+                            .count()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum()
+        });
+        assert_eq!(v.len(), 100);
+        assert_eq!(rejected, threads * per_thread - 100);
+        // exactly the rejected values were dropped, each once
+        let counts = drop_counts(drops);
+        assert_eq!(counts.iter().sum::<usize>(), rejected);
+        for t in v.iter() {
+            assert_eq!(counts[t.id], 0);
+        }
+        drop(v);
+        assert_eq!(drop_counts(drops), vec![1; threads * per_thread]);
+    }
+
+    #[test]
+    fn zero_sized() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug)]
+        struct Z;
+        impl Drop for Z {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let mut buf: [MaybeUninit<Z>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.capacity(), 8);
+        for i in 0..5 {
+            assert_eq!(v.push(Z).unwrap(), i);
+        }
+        assert_eq!(v.len(), 5);
+        assert_eq!(v.as_slice().len(), 5);
+        assert!(v.get(4).is_some());
+        assert!(v.get(5).is_none());
+        assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+        drop(v);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 5);
+    }
+
+    #[test]
+    fn zero_sized_huge_capacity() {
+        let buf: &mut [MaybeUninit<()>] = unsafe {
+            std::slice::from_raw_parts_mut(std::ptr::NonNull::dangling().as_ptr(), usize::MAX)
+        };
+        let v = AbaoVec::new(buf);
+        assert_eq!(v.capacity(), usize::MAX);
+        v.push(()).unwrap();
+        // pretend the vector is filled up to the last slot
+        v.set_next_idx(usize::MAX - 1);
+        v.set_confirmed_len(usize::MAX - 1);
+        assert_eq!(v.push(()), Ok(usize::MAX - 1));
+        assert_eq!(v.push(()), Err((OomError, ())));
+        assert_eq!(v.len(), usize::MAX);
+        assert_eq!(v.as_slice().len(), usize::MAX);
+    }
+
+    #[test]
+    fn clear_refill() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..8).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let ptr = buf.as_ptr() as *const Counted<'_>;
+        let mut v = AbaoVec::new(&mut buf[..]);
+        for id in 0..4 {
+            v.push(Counted { id, drops }).unwrap();
+        }
+        v.clear();
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.headroom(), 4);
+        assert_eq!(drop_counts(drops), [1, 1, 1, 1, 0, 0, 0, 0]);
+
+        // the same buffer is filled again
+        for id in 4..8 {
+            assert_eq!(v.push(Counted { id, drops }).unwrap(), id - 4);
+        }
+        assert_eq!(v.as_ptr(), ptr);
+        assert_eq!(v.iter().map(|t| t.id).collect::<Vec<_>>(), [4, 5, 6, 7]);
+        drop(v);
+        assert_eq!(drop_counts(drops), [1; 8]);
+    }
+
+    #[test]
+    fn high_water_mark() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.high_water_mark(), 0);
+        for i in 0..6 {
+            v.push(i).unwrap();
+        }
+        assert_eq!(v.high_water_mark(), 6);
+        v.clear();
+        for i in 0..4 {
+            v.push(i).unwrap();
+        }
+        assert_eq!(v.high_water_mark(), 6);
+        v.clear();
+        assert_eq!(v.high_water_mark(), 6);
+        for i in 0..8 {
+            v.push(i).unwrap();
+        }
+        assert_eq!(v.high_water_mark(), 8);
+        unsafe { v.set_len(2) };
+        assert_eq!(v.high_water_mark(), 8);
+    }
+
+    #[test]
+    fn seq_clear_refill() {
+        use std::sync::atomic::AtomicUsize;
+        let mut buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let mut v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        for i in 0..4 {
+            v.push(i.to_string()).unwrap();
+        }
+        v.clear();
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.get(0), None);
+        v.push("a".to_string()).unwrap();
+        assert_eq!(v.as_slice(), &["a"]);
+        assert_eq!(v.get(1), None);
+    }
+
+    #[test]
+    fn into_parts() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..3).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(Counted { id: 0, drops }).unwrap();
+        v.push(Counted { id: 1, drops }).unwrap();
+        // pretend a push claimed slot 2, but never published it
+        v.set_next_idx(3);
+        v.push(Counted { id: 2, drops }).unwrap();
+
+        let (elements, spare) = v.into_parts();
+        // only the element after the gap was dropped
+        assert_eq!(drop_counts(drops), [0, 0, 1]);
+        assert_eq!(elements.iter().map(|c| c.id).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(spare.len(), 2);
+        elements.swap(0, 1);
+        assert_eq!(elements[0].id, 1);
+        // the elements are owned here now
+        unsafe { core::ptr::drop_in_place(elements) };
+        assert_eq!(drop_counts(drops), [1, 1, 1]);
+    }
+
+    #[test]
+    fn split_spare() {
+        let mut buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push("a".to_string()).unwrap();
+        let (first, v) = v.split_spare();
+        v.push("b".to_string()).unwrap();
+        let (second, v) = v.split_spare();
+        assert_eq!(v.capacity(), 2);
+        v.push("c".to_string()).unwrap();
+        v.push("d".to_string()).unwrap();
+        assert!(v.push("e".to_string()).is_err());
+        assert_eq!(first, &["a"]);
+        assert_eq!(second, &["b"]);
+        assert_eq!(v.as_slice(), &["c", "d"]);
+        // the split off elements are owned here now
+        unsafe {
+            core::ptr::drop_in_place(first);
+            core::ptr::drop_in_place(second);
+        }
+    }
+
+    #[test]
+    fn freeze_leaks() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..2).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let frozen = {
+            let v = AbaoVec::new(&mut buf[..]);
+            v.push(Counted { id: 0, drops }).unwrap();
+            v.push(Counted { id: 1, drops }).unwrap();
+            v.freeze()
+        };
+        assert_eq!(frozen.iter().map(|c| c.id).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(drop_counts(drops), [0, 0]);
+    }
+
+    #[test]
+    fn is_full_claimed() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        let range = v.reserve(3).unwrap();
+        // the reserved slots are taken, even though they are not confirmed
+        assert!(v.is_full());
+        assert_eq!(v.remaining_capacity(), 3);
+        unsafe {
+            for idx in range.clone() {
+                v.as_mut_ptr().add(idx).write(idx as u8);
+            }
+            v.commit(range).unwrap();
+        }
+        assert!(v.is_full());
+        v.clear();
+        assert!(!v.is_full());
+    }
+
+    #[test]
+    fn truncate() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..5).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        for id in 0..4 {
+            v.push(Counted { id, drops }).unwrap();
+        }
+        v.truncate(4);
+        assert_eq!(v.len(), 4);
+        v.truncate(2);
+        assert_eq!(drop_counts(drops), [0, 0, 1, 1, 0]);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.claimed_len(), 2);
+        assert_eq!(v.high_water_mark(), 4);
+        assert_eq!(v.push(Counted { id: 4, drops }).unwrap(), 2);
+        drop(v);
+        assert_eq!(drop_counts(drops), [1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn seq_truncate_after_gap() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..3).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let mut v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(Counted { id: 0, drops }).unwrap();
+        v.push(Counted { id: 1, drops }).unwrap();
+        // pretend a push claimed slot 2, but never published it
+        v.set_next_idx(3);
+        v.push(Counted { id: 2, drops }).unwrap();
+        assert_eq!(v.len(), 2);
+
+        // the element published after the gap is dropped as well
+        v.truncate(1);
+        assert_eq!(drop_counts(drops), [0, 1, 1]);
+        assert_eq!(v.get(3).map(|c| c.id), None);
+        assert_eq!(v.push(Counted { id: 1, drops }).unwrap(), 1);
+        assert_eq!(v.push(Counted { id: 2, drops }).unwrap(), 2);
+        assert_eq!(v.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_vec() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for id in 0..4 {
+            v.push(Counted { id, drops }).unwrap();
+        }
+        let vec = v.into_vec();
+        // the elements moved, nothing was dropped
+        assert_eq!(drop_counts(drops), [0; 4]);
+        assert_eq!(vec.iter().map(|t| t.id).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        drop(vec);
+        assert_eq!(drop_counts(drops), [1; 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn seq_into_vec_after_gap() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(Counted { id: 0, drops }).unwrap();
+        // pretend a push claimed slot 1, but never published it
+        v.set_next_idx(2);
+        v.push(Counted { id: 2, drops }).unwrap();
+        let vec = v.into_vec();
+        // the element after the gap is not part of the vector
+        assert_eq!(drop_counts(drops), [0, 0, 1, 0]);
+        assert_eq!(vec.len(), 1);
+        drop(vec);
+        assert_eq!(drop_counts(drops), [1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn try_push_many() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..10).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let mut buf: [MaybeUninit<Counted<'_>>; 6] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let counted = |ids: std::ops::Range<usize>| {
+            ids.map(|id| Counted { id, drops })
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+
+        // all fit
+        let mut all = counted(0..3).peekable();
+        assert_eq!(v.try_push_many(&mut all), 3);
+        assert!(all.next().is_none());
+
+        // some fit
+        let mut some = counted(3..8).peekable();
+        assert_eq!(v.try_push_many(&mut some), 3);
+        assert_eq!(v.len(), 6);
+
+        // none fit, the element which did not fit is not lost
+        assert_eq!(v.try_push_many(&mut some), 0);
+        assert_eq!(drop_counts(drops), [0; 10]);
+        assert_eq!(some.peek().map(|t| t.id), Some(6));
+        let mut none = counted(8..10).peekable();
+        assert_eq!(v.try_push_many(&mut none), 0);
+        assert_eq!(drop_counts(drops), [0; 10]);
+
+        // the leftover elements are dropped by their iterators
+        drop(some);
+        drop(none);
+        assert_eq!(drop_counts(drops), [0, 0, 0, 0, 0, 0, 1, 1, 1, 1]);
+        assert_eq!(
+            v.iter().map(|t| t.id).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5]
+        );
+        drop(v);
+        assert_eq!(drop_counts(drops), [1; 10]);
+    }
+
+    #[test]
+    fn extend_checked() {
+        use std::sync::atomic::AtomicUsize;
+        let drops: Vec<AtomicUsize> = (0..6).map(|_| AtomicUsize::new(0)).collect();
+        let drops = &drops[..];
+        let counted =
+            |ids: std::ops::Range<usize>| ids.map(|id| Counted { id, drops }).collect::<Vec<_>>();
+        let mut buf: [MaybeUninit<Counted<'_>>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+
+        assert_eq!(v.extend_checked(counted(0..2)).ok(), Some(2));
+        let (pushed, OomError, mut rest) = v.extend_checked(counted(2..6)).unwrap_err();
+        assert_eq!(pushed, 2);
+        // the element which did not fit was not consumed
+        assert_eq!(drop_counts(drops), [0; 6]);
+        assert_eq!(rest.next().map(|t| t.id), Some(4));
+        assert_eq!(drop_counts(drops), [0, 0, 0, 0, 1, 0]);
+        drop(rest);
+        assert_eq!(drop_counts(drops), [0, 0, 0, 0, 1, 1]);
+        drop(v);
+        assert_eq!(drop_counts(drops), [1; 6]);
+    }
+
+    #[test]
+    fn new_zeroed() {
+        let mut buf: [MaybeUninit<u32>; 4] = [MaybeUninit::new(u32::MAX); 4];
+        let v = AbaoVec::new_zeroed(&mut buf[..]);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.get(0), None);
+        let bytes = unsafe { std::slice::from_raw_parts(v.as_ptr().cast::<u8>(), 16) };
+        assert!(bytes.iter().all(|&b| b == 0));
+        v.push(1).unwrap();
+        v.push(2).unwrap();
+        assert_eq!(v.as_slice(), &[1, 2]);
+        drop(v);
+        let values: Vec<u32> = buf.iter().map(|s| unsafe { s.assume_init() }).collect();
+        assert_eq!(values, [1, 2, 0, 0]);
+    }
+
+    #[test]
+    fn spare_capacity_set_len() {
+        let mut buf: [MaybeUninit<String>; 6] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        v.push("a".to_string()).unwrap();
+        let spare = v.spare_capacity_mut();
+        assert_eq!(spare.len(), 5);
+        for (i, slot) in spare.iter_mut().take(3).enumerate() {
+            slot.write(i.to_string());
+        }
+        unsafe { v.set_len(4) };
+        assert_eq!(v.as_slice(), &["a", "0", "1", "2"]);
+        assert_eq!(v.push("b".to_string()), Ok(4));
+        assert_eq!(v.spare_capacity_mut().len(), 1);
+    }
+
+    #[test]
+    fn seq_spare_capacity_set_len() {
+        use std::sync::atomic::AtomicUsize;
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let mut v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(0).unwrap();
+        for slot in v.spare_capacity_mut() {
+            slot.write(9);
+        }
+        unsafe { v.set_len(3) };
+        assert_eq!(v.as_slice(), &[0, 9, 9]);
+        // lowering the length unpublishes the slots after it
+        unsafe { v.set_len(1) };
+        assert_eq!(v.get(1), None);
+        assert_eq!(v.push(1), Ok(1));
+        assert_eq!(v.get(2), None);
+    }
+
+    #[test]
+    fn zero_length() {
+        let v = AbaoVec::new(&mut []);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.as_slice(), &[]);
+        assert_eq!(v.push(0_u8), Err((OomError, 0)));
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.as_slice(), &[]);
+    }
+
+    #[test]
+    fn single_length() {
+        let mut buf: [MaybeUninit<u8>; 1] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.as_slice(), &[]);
+        v.push(0).unwrap();
+        assert_eq!(v.len(), 1);
+        assert_eq!(v.as_slice(), &[0]);
+        assert_eq!(v.push(1), Err((OomError, 1)));
+        assert_eq!(v.len(), 1);
+        assert_eq!(v.as_slice(), &[0]);
+    }
+
+    #[test]
+    fn push_full_returns_value() {
+        let mut buf: [MaybeUninit<Box<u32>>; 1] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(Box::new(0)).unwrap();
+        let b = Box::new(1);
+        let ptr: *const u32 = &*b;
+        let (err, back) = v.push(b).unwrap_err();
+        assert_eq!(err, OomError);
+        // the very same allocation is handed back
+        assert_eq!(&*back as *const u32, ptr);
+        assert_eq!(*back, 1);
+        assert_eq!(v.as_slice(), &[Box::new(0)]);
+    }
+
+    #[test]
+    fn panicking_clone() {
+        use std::panic::{self, AssertUnwindSafe};
+        #[derive(Debug, PartialEq)]
+        struct P(u8);
+        impl Clone for P {
+            fn clone(&self) -> Self {
+                if self.0 == 2 {
+                    panic!("clone of P(2)");
+                }
+                P(self.0)
+            }
+        }
+        let mut buf: [MaybeUninit<P>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let src = [P(0), P(1), P(2), P(3)];
+        // the clone panics in the middle of the claimed block
+        let res = panic::catch_unwind(AssertUnwindSafe(|| v.extend_exact(src.iter().cloned())));
+        assert!(res.is_err());
+        // the written prefix is confirmed and the rest of the block given back
+        assert_eq!(v.as_slice(), &[P(0), P(1)]);
+        assert_eq!(v.claimed_len(), 2);
+        assert_eq!(v.push(P(3)), Ok(2));
+
+        let mut other_buf: [MaybeUninit<P>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let other = AbaoVec::new(&mut other_buf[..]);
+        for p in [P(4), P(2), P(5)] {
+            other.push(p).unwrap();
+        }
+        let res = panic::catch_unwind(AssertUnwindSafe(|| v.append_from(&other)));
+        assert!(res.is_err());
+        assert_eq!(v.as_slice(), &[P(0), P(1), P(3), P(4)]);
+        // later pushes get the next indices
+        assert_eq!(v.push(P(6)), Ok(4));
+        assert_eq!(v.push(P(7)), Ok(5));
+        assert_eq!(v.as_slice(), &[P(0), P(1), P(3), P(4), P(6), P(7)]);
+    }
+
+    #[test]
+    fn concurrent_push_complete() {
+        use std::thread;
+        const CAP: usize = 1 << 16;
+        let threads = 16;
+        let mut buf: Vec<MaybeUninit<usize>> = (0..CAP).map(|_| MaybeUninit::uninit()).collect();
+        let v = &AbaoVec::new(&mut buf[..]);
+        thread::scope(|s| {
+            for t in 0..threads {
+                s.spawn(move || {
+                    for i in (t..CAP).step_by(threads) {
+                        v.push(i).unwrap();
+                    }
+                });
+            }
+        });
+        let mut values = v.as_slice().to_vec();
+        values.sort_unstable();
+        assert!(values.iter().copied().eq(0..CAP));
+    }
+
+    #[test]
+    fn push_unsync_same_contents() {
+        use std::sync::atomic::AtomicUsize;
+        let mut a: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut b: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut c: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..8).map(|_| AtomicUsize::new(0)).collect();
+        let sync = AbaoVec::new(&mut a[..]);
+        let unsync = AbaoVec::new(&mut b[..]);
+        let unsync_seq = AbaoVec::new_seq(&mut c[..], &mut seq[..]);
+        for i in 0..10 {
+            let expected = sync.push(i.to_string());
+            assert_eq!(unsafe { unsync.push_unsync(i.to_string()) }, expected);
+            assert_eq!(unsafe { unsync_seq.push_unsync(i.to_string()) }, expected);
+        }
+        assert_eq!(sync, unsync);
+        assert_eq!(sync, unsync_seq);
+        assert_eq!(unsync_seq.get(7), Some(&"7".to_string()));
+        // synchronized pushes continue where the unsynchronized ones stopped
+        let mut d: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut d[..]);
+        unsafe { v.push_unsync(0) }.unwrap();
+        assert_eq!(v.push(1), Ok(1));
+        assert_eq!(unsafe { v.push_unsync(2) }, Ok(2));
+        assert_eq!(v.as_slice(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn push_with_claimed_first() {
+        let mut buf: [MaybeUninit<usize>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4 {
+            // the slot is claimed, but not confirmed, while `f` runs
+            let idx = v.push_with(|| {
+                assert_eq!(v.len(), i);
+                v.claimed_len() - 1
+            });
+            assert_eq!(idx, Ok(i));
+        }
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+        let mut called = false;
+        assert_eq!(
+            v.push_with(|| {
+                called = true;
+                4
+            }),
+            Err(OomError)
+        );
+        assert!(!called);
+    }
+
+    #[test]
+    fn push_with_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+        let mut buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push_with(|| "a".to_string()).unwrap();
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.push_with(|| panic!("constructing the value"))
+        }));
+        assert!(res.is_err());
+        // the slot was given back and later pushes make progress
+        assert_eq!(v.push("b".to_string()), Ok(1));
+        assert_eq!(v.push_with(|| "c".to_string()), Ok(2));
+        assert_eq!(v.as_slice(), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn push_with_panic_concurrent_push() {
+        use std::panic::{self, AssertUnwindSafe};
+        use std::sync::mpsc;
+        use std::thread;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        let (claimed_tx, claimed_rx) = mpsc::channel();
+        thread::scope(|s| {
+            let v = &v;
+            let pusher = s.spawn(move || {
+                claimed_rx.recv().unwrap();
+                v.push(2)
+            });
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                v.push_with(|| {
+                    claimed_tx.send(()).unwrap();
+                    // wait for the pusher to claim the slot behind this one
+                    while v.next_idx.load(std::sync::atomic::Ordering::Relaxed) < 3 {
+                        thread::yield_now();
+                    }
+                    panic!("constructing the value")
+                })
+            }));
+            assert!(res.is_err());
+            // the pusher does not wait forever, but gets its value back
+            assert_eq!(pusher.join().unwrap(), Err((OomError, 2)));
+        });
+        assert_eq!(v.as_slice(), &[0]);
+        // the sealed vector refuses every push
+        assert_eq!(v.push(3), Err((OomError, 3)));
+        assert_eq!(v.push_with(|| 3), Err(OomError));
+        assert_eq!(v.extend_exact(vec![3]), Err(OomError));
+        assert!(v.reserve(1).is_err());
+
+        // until it is reset
+        v.clear();
+        assert_eq!(v.push(3), Ok(0));
+        assert_eq!(v.push_with(|| 4), Ok(1));
+        assert_eq!(v.as_slice(), &[3, 4]);
+    }
+
+    #[test]
+    fn seq_regular() {
+        use std::sync::atomic::AtomicUsize;
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..8).map(AtomicUsize::new).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.get(0), None);
+        assert_eq!(v.push(0), Ok(0));
+        assert_eq!(v.push(1), Ok(1));
+        assert_eq!(v.push(2), Ok(2));
+        assert_eq!(v.push(3), Ok(3));
+        assert_eq!(v.push(4), Err((OomError, 4)));
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.get(3), Some(&3));
+        assert_eq!(v.get(4), None);
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn seq_get_after_gap() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..4).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(0).unwrap();
+        // pretend the push to index 1 is still in flight
+        v.next_idx.store(2, Ordering::Relaxed);
+        v.push(2).unwrap();
+        assert_eq!(v.len(), 1);
+        assert_eq!(v.as_slice(), &[0]);
+        assert_eq!(v.get(1), None);
+        assert_eq!(v.get(2), Some(&2));
+    }
+
+    #[test]
+    fn seq_dropable() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug)]
+        struct X;
+        impl Drop for X {
+            fn drop(&mut self) {
+                COUNT.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let mut buf: [MaybeUninit<X>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..8).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        v.push(X).unwrap();
+        // leave a gap at index 1, the element after it must be dropped anyway
+        v.next_idx.store(2, Ordering::Relaxed);
+        v.push(X).unwrap();
+        assert_eq!(v.len(), 1);
+        drop(v);
+        assert_eq!(COUNT.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn push_unconfirmed() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let mut buf: [MaybeUninit<usize>; 64] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..64).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        for i in 0..32 {
+            assert_eq!(v.push_unconfirmed(i), Ok(i));
+        }
+        // nothing advanced the confirmed length yet
+        assert_eq!(v.confirmed_len.load(Ordering::Relaxed), 0);
+        assert_eq!(v.get(31), Some(&31));
+        assert_eq!(v.reconcile(), 32);
+        // leave a gap at index 40
+        for i in 32..40 {
+            v.push_unconfirmed(i).unwrap();
+        }
+        v.next_idx.store(41, Ordering::Relaxed);
+        for i in 41..64 {
+            v.push_unconfirmed(i).unwrap();
+        }
+        assert_eq!(v.push_unconfirmed(64), Err((OomError, 64)));
+        assert_eq!(v.confirmed_len.load(Ordering::Relaxed), 32);
+        assert_eq!(v.len(), 40);
+        assert_eq!(v.as_slice(), &(0..40).collect::<Vec<_>>()[..]);
+        assert_eq!(v.get(63), Some(&63));
+    }
+
+    /// An iterator reporting a wrong length
+    struct Lying<I>(I, usize);
+
+    impl<I: Iterator> Iterator for Lying<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.1, Some(self.1))
+        }
+    }
+
+    impl<I: Iterator> ExactSizeIterator for Lying<I> {}
+
+    #[test]
+    fn extend_exact() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        assert_eq!(v.extend_exact(vec![1, 2, 3]), Ok(1));
+        // the whole block was claimed and confirmed at once
+        assert_eq!(v.next_idx.load(std::sync::atomic::Ordering::Relaxed), 4);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.extend_exact(Vec::new()), Ok(4));
+        assert_eq!(v.extend_exact(vec![4, 5, 6, 7, 8]), Err(OomError));
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(v.extend_exact(vec![4, 5, 6, 7]), Ok(4));
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn extend_exact_short_iter() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.extend_exact(Lying(0..2, 4)), Ok(0));
+        assert_eq!(v.as_slice(), &[0, 1]);
+        // the unused slots were given back
+        assert_eq!(v.push(2), Ok(2));
+        assert_eq!(v.as_slice(), &[0, 1, 2]);
+        // a long iterator is cut to the reported length
+        assert_eq!(v.extend_exact(Lying(3.., 2)), Ok(3));
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn reserve_commit_reverse() {
+        use std::thread;
+        let mut buf: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = &AbaoVec::new(&mut buf[..]);
+        let first = v.reserve(2).unwrap();
+        let second = v.reserve(3).unwrap();
+        assert_eq!(first, 0..2);
+        assert_eq!(second, 2..5);
+        thread::scope(|s| {
+            let later = s.spawn(|| {
+                let ptr = v.as_mut_ptr();
+                for idx in second.clone() {
+                    unsafe { ptr.add(idx).write(idx.to_string()) };
+                }
+                unsafe { v.commit(second.clone()) }
+            });
+            // the later range stays unconfirmed behind the earlier one
+            thread::yield_now();
+            assert_eq!(v.len(), 0);
+            let ptr = v.as_mut_ptr();
+            for idx in first.clone() {
+                unsafe { ptr.add(idx).write(idx.to_string()) };
             }
+            unsafe { v.commit(first.clone()) }.unwrap();
+            later.join().unwrap().unwrap();
+        });
+        assert_eq!(v.as_slice(), &["0", "1", "2", "3", "4"]);
+        assert_eq!(v.push("5".to_string()), Ok(5));
+    }
+
+    #[test]
+    fn seq_reserve_commit_reverse() {
+        use std::sync::atomic::AtomicUsize;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..8).map(AtomicUsize::new).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        let first = v.reserve(2).unwrap();
+        let second = v.reserve(3).unwrap();
+        let ptr = v.as_mut_ptr();
+        for idx in second.clone() {
+            unsafe { ptr.add(idx).write(idx as u8) };
+        }
+        // committing out of order does not wait
+        unsafe { v.commit(second) }.unwrap();
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.get(3), Some(&3));
+        for idx in first.clone() {
+            unsafe { ptr.add(idx).write(idx as u8) };
         }
+        unsafe { v.commit(first) }.unwrap();
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4]);
     }
-}
 
-unsafe impl<'a, T> Send for AbaoVec<'a, T> where T: Send {} // TODO: check safety
-unsafe impl<'a, T> Sync for AbaoVec<'a, T> where T: Sync {} // TODO: check safety
+    #[test]
+    fn extend_from_slice_concurrent_push() {
+        use std::thread;
+        const THREADS: usize = 4;
+        const ROUNDS: usize = 64;
+        let mut buf: Vec<MaybeUninit<usize>> = (0..THREADS * ROUNDS * 4)
+            .map(|_| MaybeUninit::uninit())
+            .collect();
+        let v = &AbaoVec::new(&mut buf[..]);
+        thread::scope(|s| {
+            for t in 0..THREADS {
+                s.spawn(move || {
+                    for r in 0..ROUNDS {
+                        let base = (t * ROUNDS + r) * 4;
+                        if t % 2 == 0 {
+                            let range = v.extend_from_slice(&[base, base + 1, base + 2]).unwrap();
+                            assert_eq!(range.len(), 3);
+                            assert_eq!(v.get(range.start), Some(&base));
+                            v.push(base + 3).unwrap();
+                        } else {
+                            for i in base..base + 4 {
+                                v.push(i).unwrap();
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        assert_eq!(v.len(), THREADS * ROUNDS * 4);
+        assert_eq!(v.extend_from_slice(&[0]), Err(OomError));
+        let mut all = v.as_slice().to_vec();
+        // blocks are contiguous
+        for w in all.windows(3) {
+            let thread = w[0] / 4 / ROUNDS;
+            if w[0] % 4 == 0 && thread.is_multiple_of(2) {
+                assert_eq!(w, &[w[0], w[0] + 1, w[0] + 2]);
+            }
+        }
+        all.sort_unstable();
+        assert_eq!(all, (0..THREADS * ROUNDS * 4).collect::<Vec<_>>());
+    }
 
-impl<'a, T> fmt::Debug for AbaoVec<'a, T>
-where
-    T: fmt::Debug,
-{
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_list().entries(self.as_slice().iter()).finish()
+    #[test]
+    fn extend_exact_panicking_iter() {
+        use std::panic::{self, AssertUnwindSafe};
+        let mut buf: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push("a".to_string()).unwrap();
+        let iter = (0..5).map(|i| {
+            if i == 2 {
+                panic!("third element");
+            }
+            i.to_string()
+        });
+        let res = panic::catch_unwind(AssertUnwindSafe(|| v.extend_exact(iter)));
+        assert!(res.is_err());
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.as_slice(), &["a", "0", "1"]);
+        // the rest of the block was given back
+        assert_eq!(v.push("b".to_string()), Ok(3));
+        assert_eq!(v.as_slice(), &["a", "0", "1", "b"]);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::AbaoVec;
-    use crate::OomError;
-    use std::mem::MaybeUninit;
+    /// Runs `give_back` while an earlier `push_with` is still in flight.
+    /// `give_back` claims the slot after it and gives it back unwritten.
+    /// Then a later push takes the given back slot
+    /// and confirms it right after the earlier push.
+    ///
+    /// Panics if `give_back` waits for the earlier push.
+    fn give_back_in_flight<'v, F>(v: &'v AbaoVec<'v, u8>, give_back: F)
+    where
+        F: FnOnce() + Send + 'v,
+    {
+        use std::sync::mpsc;
+        use std::thread;
+        use std::time::Duration;
+        let (claimed_tx, claimed_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (done_tx, done_rx) = mpsc::channel();
+        let idx = thread::scope(|s| {
+            let earlier = s.spawn(move || {
+                v.push_with(|| {
+                    claimed_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                    0
+                })
+            });
+            claimed_rx.recv().unwrap();
+            let idx = v.claimed_len();
+            s.spawn(move || {
+                give_back();
+                done_tx.send(()).unwrap();
+            });
+            if done_rx.recv_timeout(Duration::from_secs(10)).is_err() {
+                // let the stuck thread go on before failing
+                release_tx.send(()).unwrap();
+                panic!("giving back the slot waited for the earlier push");
+            }
+            assert_eq!(v.claimed_len(), idx);
+
+            let later = s.spawn(move || v.push(1));
+            while v.claimed_len() == idx {
+                thread::yield_now();
+            }
+            release_tx.send(()).unwrap();
+            assert_eq!(earlier.join().unwrap(), Ok(idx - 1));
+            assert_eq!(later.join().unwrap(), Ok(idx));
+            idx
+        });
+        assert_eq!(v.len(), idx + 1);
+        assert_eq!(&v[idx - 1..], &[0, 1]);
+    }
 
-    // regular behavior to be run by miri
     #[test]
-    fn regular() {
-        let mut buf: [MaybeUninit<u8>; 128] = unsafe { MaybeUninit::uninit().assume_init() };
+    fn extend_exact_empty_short_iter_in_flight() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        give_back_in_flight(&v, || {
+            assert_eq!(v.extend_exact(Lying(core::iter::empty(), 1)), Ok(1));
+        });
+    }
+
+    #[test]
+    fn push_with_panic_in_flight() {
+        use std::panic::{self, AssertUnwindSafe};
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        give_back_in_flight(&v, || {
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                v.push_with(|| panic!("constructing the value"))
+            }));
+            assert!(res.is_err());
+        });
+    }
+
+    #[test]
+    fn reserve_uninit_drop_in_flight() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        give_back_in_flight(&v, || drop(v.reserve_uninit(1).unwrap()));
+    }
+
+    #[test]
+    fn reserve_uninit_commit_nothing_in_flight() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        give_back_in_flight(&v, || {
+            let slots = v.reserve_uninit(2).unwrap();
+            assert_eq!(unsafe { slots.commit(0) }, Ok(1..1));
+        });
+    }
+
+    #[test]
+    fn extend_exact_panicking_iter_in_flight() {
+        use std::panic::{self, AssertUnwindSafe};
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        give_back_in_flight(&v, || {
+            let iter = (0..3).map(|_| -> u8 { panic!("first element") });
+            let res = panic::catch_unwind(AssertUnwindSafe(|| v.extend_exact(iter)));
+            assert!(res.is_err());
+        });
+    }
+
+    #[test]
+    fn extend_exact_short_iter_sealed() {
+        use std::sync::atomic::AtomicUsize;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut seq: Vec<AtomicUsize> = (0..8).map(|_| AtomicUsize::new(0)).collect();
+        let v = AbaoVec::new_seq(&mut buf[..], &mut seq[..]);
+        let mut behind = None;
+        let iter = (0..4).inspect(|&i| {
+            if i == 1 {
+                // claims the slot behind the block
+                behind = Some(v.reserve_uninit(1).unwrap());
+            }
+        });
+        assert_eq!(v.extend_exact(Lying(iter.take(2), 4)), Ok(0));
+        assert_eq!(v.as_slice(), &[0, 1]);
+        // the gap can not be given back, so the vector is sealed
+        assert_eq!(v.push(5), Err((OomError, 5)));
+
+        // the slot claimed before the seal is not published after it
+        let mut behind = behind.unwrap();
+        assert_eq!(behind.range(), 4..5);
+        behind.as_uninit_mut()[0].write(9);
+        assert_eq!(unsafe { behind.commit(1) }, Err(OomError));
+        assert_eq!(v.get(4), None);
+        assert_eq!(v.as_slice(), &[0, 1]);
+    }
+
+    #[test]
+    fn extend_exact_sealed_concurrent_push() {
+        use std::sync::mpsc;
+        use std::thread;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = &AbaoVec::new(&mut buf[..]);
+        let (claimed_tx, claimed_rx) = mpsc::channel();
+        let (pushed_tx, pushed_rx) = mpsc::channel();
+        thread::scope(|s| {
+            let pusher = s.spawn(move || {
+                claimed_rx.recv().unwrap();
+                pushed_tx.send(()).unwrap();
+                v.push(9)
+            });
+            let iter = (0..2).inspect(|&i| {
+                if i == 1 {
+                    claimed_tx.send(()).unwrap();
+                    pushed_rx.recv().unwrap();
+                    // give the pusher some time to claim its slot
+                    while v.next_idx.load(std::sync::atomic::Ordering::Relaxed) < 5 {
+                        thread::yield_now();
+                    }
+                }
+            });
+            assert_eq!(v.extend_exact(Lying(iter, 4)), Ok(0));
+            // the pusher claimed a slot behind the gap and gives up
+            // the value is handed back
+            assert_eq!(pusher.join().unwrap(), Err((OomError, 9)));
+        });
+        assert_eq!(v.as_slice(), &[0, 1]);
+        assert_eq!(v.push(2), Err((OomError, 2)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invariant violation")]
+    fn strict_invariants_len() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
         let v = AbaoVec::new(&mut buf[..]);
-        assert_eq!(v.len(), 0);
-        assert_eq!(v.as_slice(), &[]);
         v.push(0).unwrap();
-        assert_eq!(v.len(), 1);
         v.push(1).unwrap();
-        assert_eq!(v.len(), 2);
-        v.push(2).unwrap();
-        assert_eq!(v.len(), 3);
-        assert_eq!(v.get(0), Some(&0));
-        assert_eq!(v.get(1), Some(&1));
-        assert_eq!(v.get(2), Some(&2));
-        assert_eq!(v.as_slice(), &[0, 1, 2]);
+        // pretend fewer slots were claimed than confirmed
+        v.set_next_idx(1);
+        v.len();
     }
 
     #[test]
-    fn dropable() {
-        use std::sync::atomic::AtomicUsize;
+    fn append_from() {
+        let mut a_buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let a = AbaoVec::new(&mut a_buf[..]);
+        let mut b_buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let b = AbaoVec::new(&mut b_buf[..]);
+        for i in 0..3 {
+            a.push(format!("a{}", i)).unwrap();
+            b.push(format!("b{}", i)).unwrap();
+        }
+        // the claimed but unconfirmed slot is not part of the snapshot
+        b.reserve(1).unwrap();
+
+        let mut buf: [MaybeUninit<String>; 7] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.append_from(&a), Ok(0..3));
+        assert_eq!(v.append_from(&b), Ok(3..6));
+        assert_eq!(v.as_slice(), &["a0", "a1", "a2", "b0", "b1", "b2"]);
+
+        // the vector stays unchanged if the other one does not fit
+        assert_eq!(v.append_from(&a), Err(OomError));
+        assert_eq!(v.len(), 6);
+        assert_eq!(v.claimed_len(), 6);
+        assert_eq!(v.push("c".to_string()), Ok(6));
+    }
+
+    #[test]
+    fn corrupted_len_in_bounds() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4 {
+            v.push(i).unwrap();
+        }
+        for len in [5, 1000, usize::MAX] {
+            v.set_confirmed_len(len);
+            assert_eq!(v.len(), 4);
+            assert_eq!(v.get(4), None);
+            assert_eq!(v.get(len - 1), None);
+            assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+            assert_eq!(v.iter().count(), 4);
+        }
+    }
+
+    #[test]
+    fn slice_unchecked() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(unsafe { v.slice_unchecked(0..0) }, &[]);
+        for i in 0..8 {
+            v.push(i).unwrap();
+        }
+        let len = v.len();
+        for start in 0..=len {
+            for end in start..=len {
+                let slice = unsafe { v.slice_unchecked(start..end) };
+                assert_eq!(slice, &v.as_slice()[start..end]);
+            }
+        }
+    }
+
+    #[test]
+    fn headroom_in_flight() {
         use std::sync::atomic::Ordering;
-        static COUNT: AtomicUsize = AtomicUsize::new(0);
-        struct X;
-        impl X {
-            fn new() -> X {
-                COUNT.fetch_add(1, Ordering::Relaxed);
-                X
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.capacity(), 8);
+        v.push(0).unwrap();
+        assert_eq!(v.headroom(), 7);
+        assert_eq!(v.remaining_capacity(), 7);
+        // pretend three pushes claimed their slots but did not finish yet
+        v.next_idx.store(4, Ordering::Relaxed);
+        assert_eq!(v.headroom(), 4);
+        assert_eq!(v.remaining_capacity(), 7);
+        // pretend the vector was sealed before the claimed slots
+        v.limit.store(2, Ordering::Relaxed);
+        assert_eq!(v.headroom(), 0);
+    }
+
+    #[test]
+    fn claimed_len_in_flight() {
+        use std::sync::mpsc;
+        use std::thread;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = &AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        assert_eq!(v.claimed_len(), v.len());
+        let (claimed_tx, claimed_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        thread::scope(|s| {
+            let pusher = s.spawn(move || {
+                v.push_with(|| {
+                    claimed_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                    1
+                })
+            });
+            // the pusher blocks while its slot is claimed
+            claimed_rx.recv().unwrap();
+            assert_eq!(v.claimed_len(), 2);
+            assert_eq!(v.len(), 1);
+            assert!(v.claimed_len() > v.len());
+            release_tx.send(()).unwrap();
+            assert_eq!(pusher.join().unwrap(), Ok(1));
+        });
+        assert_eq!(v.claimed_len(), 2);
+        assert_eq!(v.len(), 2);
+    }
+
+    #[test]
+    fn as_raw_parts() {
+        let mut buf: [MaybeUninit<u16>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let (ptr, len) = v.as_raw_parts();
+        assert_eq!(ptr, v.as_ptr());
+        assert_eq!(len, 0);
+        for i in 0..5 {
+            v.push(i).unwrap();
+        }
+        let (ptr, len) = v.as_raw_parts();
+        let slice = unsafe { std::slice::from_raw_parts(ptr, len) };
+        assert_eq!(slice, v.as_slice());
+        assert_eq!(ptr, v.as_ptr_range().start);
+    }
+
+    #[test]
+    fn get_blocking_delayed_producer() {
+        use std::thread;
+        use std::time::Duration;
+        let mut buf: [MaybeUninit<String>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = &AbaoVec::new(&mut buf[..]);
+        thread::scope(|s| {
+            s.spawn(|| {
+                for i in 0..4 {
+                    thread::sleep(Duration::from_millis(5));
+                    v.push(i.to_string()).unwrap();
+                }
+            });
+            for i in 0..4 {
+                assert_eq!(v.get_blocking(i), Some(&i.to_string()));
             }
+        });
+        assert_eq!(v.get_blocking(8), None);
+        // slots after a seal are never confirmed
+        v.limit.store(4, std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(v.get_blocking(4), None);
+        assert_eq!(v.get_blocking(3), Some(&"3".to_string()));
+    }
+
+    #[test]
+    fn read_paths() {
+        let mut buf: [MaybeUninit<Box<u32>>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4 {
+            v.push(Box::new(i)).unwrap();
         }
-        impl Drop for X {
-            fn drop(&mut self) {
-                COUNT.fetch_sub(1, Ordering::Relaxed);
+        let slice = v.as_slice();
+        let first = v.get(0).unwrap();
+        let last = unsafe { v.get_unchecked(3) };
+        // pushing while references are held must not invalidate them
+        v.push(Box::new(4)).unwrap();
+        assert_eq!(**first, 0);
+        assert_eq!(**last, 3);
+        assert_eq!(slice.iter().map(|b| **b).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert!(std::ptr::eq(first, &slice[0]));
+        assert_eq!(v.as_slice().len(), 5);
+    }
+
+    #[test]
+    fn snapshot_confirmed_below_claimed() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::thread;
+        let mut buf: Vec<MaybeUninit<usize>> =
+            (0..1 << 14).map(|_| MaybeUninit::uninit()).collect();
+        let v = &AbaoVec::new(&mut buf[..]);
+        let done = &AtomicBool::new(false);
+        thread::scope(|s| {
+            let producers: Vec<_> = (0..3)
+                .map(|_| {
+                    s.spawn(move || {
+                        while v.push(0).is_ok() {}
+                        done.store(true, Ordering::Relaxed);
+                    })
+                })
+                .collect();
+            let mut last = (0, 0);
+            while !done.load(Ordering::Relaxed) {
+                let (confirmed, claimed) = v.snapshot();
+                assert!(confirmed <= claimed);
+                assert!(confirmed >= last.0);
+                last = (confirmed, claimed);
             }
+            for p in producers {
+                p.join().unwrap();
+            }
+        });
+        assert_eq!(v.snapshot(), (1 << 14, 1 << 14));
+    }
+
+    #[test]
+    fn as_ptr_range() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let range = v.as_ptr_range();
+        assert_eq!(range.start, range.end);
+        for i in 0..5 {
+            v.push(i).unwrap();
         }
-        let mut buf: [MaybeUninit<X>; 128] = unsafe { MaybeUninit::uninit().assume_init() };
+        let range = v.as_ptr_range();
+        let len = unsafe { range.end.offset_from(range.start) } as usize;
+        let slice = unsafe { std::slice::from_raw_parts(range.start, len) };
+        assert_eq!(slice, v.as_slice());
+        assert_eq!(slice, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn index_out_of_bounds() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
         let v = AbaoVec::new(&mut buf[..]);
-        assert_eq!(v.len(), 0);
-        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
-        v.push(X::new()).unwrap();
-        assert_eq!(v.len(), 1);
-        assert_eq!(COUNT.load(Ordering::Relaxed), 1);
-        v.push(X::new()).unwrap();
-        assert_eq!(v.len(), 2);
-        assert_eq!(COUNT.load(Ordering::Relaxed), 2);
-        v.push(X::new()).unwrap();
-        assert_eq!(v.len(), 3);
-        assert_eq!(COUNT.load(Ordering::Relaxed), 3);
-        drop(v);
-        assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+        v.push(0).unwrap();
+        assert_eq!(v[0], 0);
+        let _ = v[1];
     }
 
     #[test]
-    fn zero_length() {
-        let v = AbaoVec::new(&mut []);
-        assert_eq!(v.len(), 0);
-        assert_eq!(v.as_slice(), &[]);
-        assert_eq!(v.push(0_u8), Err(OomError));
-        assert_eq!(v.len(), 0);
-        assert_eq!(v.as_slice(), &[]);
+    #[should_panic(expected = "range out of bounds: the len is 2 but the range is 1..3")]
+    fn range_out_of_bounds() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        v.push(1).unwrap();
+        assert_eq!(&v[1..2], &[1]);
+        let _ = &v[1..3];
     }
 
     #[test]
-    fn single_length() {
-        let mut buf: [MaybeUninit<u8>; 1] = unsafe { MaybeUninit::uninit().assume_init() };
+    #[should_panic(expected = "the len is 2 but the range is ..3")]
+    fn range_to_out_of_bounds() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
         let v = AbaoVec::new(&mut buf[..]);
-        assert_eq!(v.len(), 0);
-        assert_eq!(v.as_slice(), &[]);
         v.push(0).unwrap();
-        assert_eq!(v.len(), 1);
-        assert_eq!(v.as_slice(), &[0]);
-        assert_eq!(v.push(1), Err(OomError));
-        assert_eq!(v.len(), 1);
+        v.push(1).unwrap();
+        assert_eq!(&v[..2], &[0, 1]);
+        let _ = &v[..3];
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 2 but the range is 3..")]
+    fn range_from_out_of_bounds() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        v.push(1).unwrap();
+        assert_eq!(&v[2..], &[]);
+        let _ = &v[3..];
+    }
+
+    #[test]
+    fn into_iter_ref() {
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4 {
+            v.push(i).unwrap();
+        }
+        let mut seen = Vec::new();
+        for x in &v {
+            seen.push(*x);
+        }
+        assert_eq!(seen, v.as_slice());
+    }
+
+    #[test]
+    fn as_ref_borrow() {
+        use std::borrow::Borrow;
+        fn sum<A: AsRef<[u8]>>(a: A) -> u32 {
+            a.as_ref().iter().map(|&x| u32::from(x)).sum()
+        }
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4 {
+            v.push(i).unwrap();
+        }
+        assert_eq!(sum(&v), 6);
+        let slice: &[u8] = v.borrow();
+        v.push(4).unwrap();
+        assert_eq!(slice, &[0, 1, 2, 3]);
+        assert_eq!(sum(&v), 10);
+    }
+
+    #[test]
+    fn hash_like_slice() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        fn hash<H: Hash + ?Sized>(h: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            h.hash(&mut hasher);
+            hasher.finish()
+        }
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..3 {
+            v.push(i).unwrap();
+        }
+        assert_eq!(hash(&v), hash(&[0_u8, 1, 2][..]));
+        assert_eq!(hash(&v), hash(&vec![0_u8, 1, 2]));
+        v.push(3).unwrap();
+        assert_ne!(hash(&v), hash(&[0_u8, 1, 2][..]));
+    }
+
+    #[test]
+    fn debug_forms() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        v.push(1).unwrap();
+        let _pending = v.reserve(1).unwrap();
+        assert_eq!(format!("{:?}", v), "[0, 1]");
+        assert_eq!(format!("{:#?}", v), "[\n    0,\n    1,\n]");
+    }
+
+    #[test]
+    fn ord_like_slice() {
+        use std::cmp::Ordering;
+        let mut a_buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let a = AbaoVec::new(&mut a_buf[..]);
+        let mut b_buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let b = AbaoVec::new(&mut b_buf[..]);
+        let c = crate::AbaoArrayVec::<u8, 4>::new();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        a.push(1).unwrap();
+        b.extend_from_slice(&[0, 5]).unwrap();
+        c.extend_from_slice(&[1, 0]).unwrap();
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+        assert_eq!(a.partial_cmp(&c), Some(Ordering::Less));
+        assert!(b < c);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded in extend: the capacity is 4")]
+    fn extend_overflow() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        v.extend(1..3);
+        assert_eq!(v.as_slice(), &[0, 1, 2]);
+        v.extend(3..6);
+    }
+
+    #[test]
+    fn reserve_uninit() {
+        let mut buf: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push("0".to_string()).unwrap();
+
+        // the slots are given back when the guard is dropped
+        let mut slots = v.reserve_uninit(3).unwrap();
+        assert_eq!(slots.range(), 1..4);
+        assert_eq!(slots.as_uninit_mut().len(), 3);
+        assert!(v.reserve_uninit(1).is_err());
+        drop(slots);
+        assert_eq!(v.claimed_len(), 1);
+
+        let mut slots = v.reserve_uninit(3).unwrap();
+        for (i, slot) in slots.as_uninit_mut().iter_mut().enumerate() {
+            slot.write((i + 1).to_string());
+        }
+        assert_eq!(unsafe { slots.commit(3) }, Ok(1..4));
+        assert_eq!(v.as_slice(), &["0", "1", "2", "3"]);
+    }
+
+    #[test]
+    fn reserve_uninit_sealed() {
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        let mut first = v.reserve_uninit(2).unwrap();
+        let mut second = v.reserve_uninit(2).unwrap();
+        first.as_uninit_mut()[0].write(0);
+        second.as_uninit_mut()[0].write(2);
+        second.as_uninit_mut()[1].write(3);
+        // the unwritten slot can not be given back, so the vector is sealed
+        assert_eq!(unsafe { first.commit(1) }, Ok(0..1));
+        assert_eq!(unsafe { second.commit(2) }, Err(OomError));
         assert_eq!(v.as_slice(), &[0]);
+        assert!(v.push(1).is_err());
+    }
+
+    #[test]
+    fn iter_nth() {
+        let mut buf: [MaybeUninit<usize>; 4096] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..4096 {
+            v.push(i).unwrap();
+        }
+        // the borrowing iterator is a slice iterator, so `nth` skips in O(1)
+        let mut iter = v.iter();
+        assert_eq!(iter.nth(1000), Some(&1000));
+        assert_eq!(iter.next(), Some(&1001));
+        assert_eq!(iter.len(), 4096 - 1002);
+        assert_eq!(iter.nth(5000), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fmt_write_partial() {
+        use core::fmt::Write;
+        let mut buf: [MaybeUninit<u8>; 6] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        let (a, b) = ("abc", "defgh");
+        assert_eq!(write!(v, "{}-{}", a, b), Err(core::fmt::Error));
+        // the pieces which fit stay in the vector
+        assert_eq!(v.as_slice(), b"abc-");
+        assert_eq!(write!(v, "ü"), Ok(()));
+        // a character is never split
+        assert_eq!(write!(v, "ü"), Err(core::fmt::Error));
+        assert_eq!(core::str::from_utf8(v.as_slice()), Ok("abc-ü"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_write_partial() {
+        use std::io::Write;
+        let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut v = AbaoVec::new(&mut buf[..]);
+        assert_eq!(v.write(b"").unwrap(), 0);
+        assert_eq!(v.write(b"abc").unwrap(), 3);
+        // straddles the capacity boundary
+        assert_eq!(v.write(b"defghijk").unwrap(), 5);
+        assert_eq!(v.as_slice(), b"abcdefgh");
+        assert_eq!(v.write(b"x").unwrap(), 0);
+        assert_eq!(v.write(b"").unwrap(), 0);
+        // the next index does not move past the full buffer
+        assert_eq!(v.next_idx.load(std::sync::atomic::Ordering::Relaxed), 8);
+        assert!(v.write_all(b"x").is_err());
+        v.flush().unwrap();
+
+        let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = &AbaoVec::new(&mut buf[..]);
+        let mut w = v;
+        assert_eq!(w.write(b"abcdef").unwrap(), 4);
+        assert_eq!(v.as_slice(), b"abcd");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_chunks_sum() {
+        use rayon::prelude::*;
+        let mut buf: [MaybeUninit<usize>; 1000] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        for i in 0..1000 {
+            v.push(i).unwrap();
+        }
+        let total: usize = v
+            .par_chunks(64)
+            .map(|chunk| chunk.iter().sum::<usize>())
+            .sum();
+        assert_eq!(total, (0..1000).sum());
+        assert_eq!(v.par_chunks(64).count(), 16);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+        assert_send::<AbaoVec<'_, u8>>();
+        assert_sync::<AbaoVec<'_, u8>>();
+        assert_send::<AbaoVec<'_, std::cell::Cell<u8>>>();
+        #[cfg(feature = "alloc")]
+        assert_sync::<crate::OwnedAbaoVec<std::sync::Arc<u8>>>();
     }
 
-    // usize overflow is not tested since it takes too long
+    #[test]
+    fn next_idx_overflow() {
+        let mut buf: [MaybeUninit<u8>; 2] = unsafe { MaybeUninit::uninit().assume_init() };
+        let v = AbaoVec::new(&mut buf[..]);
+        v.push(0).unwrap();
+        v.push(1).unwrap();
+        // failing pushes do not advance the next index
+        for i in 0..1000 {
+            assert_eq!(v.push(i as u8), Err((OomError, i as u8)));
+        }
+        assert_eq!(v.next_idx.load(std::sync::atomic::Ordering::Relaxed), 2);
 
+        // pretend the next index grew up to the overflow boundary
+        for idx in [usize::MAX - 1, usize::MAX] {
+            v.set_next_idx(idx);
+            assert_eq!(v.push(2), Err((OomError, 2)));
+            assert_eq!(v.extend_exact(vec![3, 4]), Err(OomError));
+            assert_eq!(v.next_idx.load(std::sync::atomic::Ordering::Relaxed), idx);
+        }
+        assert_eq!(v.as_slice(), &[0, 1]);
+    }
 }
@@ -0,0 +1,117 @@
+//! Model checks of the push protocol using `loom`.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom`.
+//!
+//! The elements live in plain caller supplied memory which loom can not track,
+//! so these models check the index and publication protocol,
+//! i.e. that no reader can observe a slot which has not been written yet.
+#![cfg(loom)]
+#![deny(rust_2018_compatibility)]
+#![deny(rust_2018_idioms)]
+#![deny(warnings)]
+
+use abao::AbaoVec;
+use loom::sync::atomic::{AtomicUsize, Ordering};
+use loom::thread;
+use std::mem::MaybeUninit;
+
+fn model<F: Fn() + Sync + Send + 'static>(f: F) {
+    let mut builder = loom::model::Builder::new();
+    if builder.preemption_bound.is_none() {
+        builder.preemption_bound = Some(3);
+    }
+    builder.check(f);
+}
+
+fn leak_buf<T>(len: usize) -> &'static mut [MaybeUninit<T>] {
+    Box::leak((0..len).map(|_| MaybeUninit::uninit()).collect())
+}
+
+fn leak_seq(len: usize) -> &'static mut [AtomicUsize] {
+    Box::leak((0..len).map(|_| AtomicUsize::new(0)).collect())
+}
+
+#[test]
+fn seq_two_pushes_one_reader() {
+    model(|| {
+        let v: &'static AbaoVec<'static, usize> =
+            Box::leak(Box::new(AbaoVec::new_seq(leak_buf(2), leak_seq(2))));
+
+        let pushers: Vec<_> = [1, 2]
+            .iter()
+            .map(|&x| thread::spawn(move || v.push(x).unwrap()))
+            .collect();
+
+        let len = v.len();
+        assert!(v.as_slice().len() >= len);
+        for i in 0..2 {
+            match v.get(i) {
+                Some(x) => assert!(*x == 1 || *x == 2),
+                None => assert!(i >= len),
+            }
+        }
+
+        for p in pushers {
+            p.join().unwrap();
+        }
+        assert_eq!(v.len(), 2);
+        let mut s = v.as_slice().to_vec();
+        s.sort_unstable();
+        assert_eq!(s, [1, 2]);
+    });
+}
+
+/// The consistency contract of the readers:
+/// every element below an observed `len` is readable by `get`,
+/// and everything its pusher did before the `push` is visible.
+///
+/// Loom can not see the write of the element itself,
+/// so each pusher sets a flag with a relaxed store before pushing,
+/// which is only guaranteed to be visible
+/// if the reader synchronizes with the pusher.
+/// One pusher is spawned per slot of the vector.
+fn len_implies_get(v: &'static AbaoVec<'static, usize>) {
+    let flags: &'static [AtomicUsize] = leak_seq(v.capacity());
+
+    let pushers: Vec<_> = (0..v.capacity())
+        .map(|x| {
+            thread::spawn(move || {
+                flags[x].store(1, Ordering::Relaxed);
+                v.push(x).unwrap()
+            })
+        })
+        .collect();
+
+    let len = v.len();
+    for i in 0..len {
+        let x = *v.get(i).expect("element below len not readable");
+        assert_eq!(flags[x].load(Ordering::Relaxed), 1);
+    }
+
+    // with sequence numbers a push may return before
+    // an earlier one is confirmed, so check after all pushes returned
+    let idxs: Vec<usize> = pushers.into_iter().map(|p| p.join().unwrap()).collect();
+    for i in idxs {
+        assert!(v.len() > i);
+        assert!(v.get(i).is_some());
+    }
+}
+
+/// The reader may load the confirmed length written by the second push,
+/// which has to make the element of the first push visible as well.
+#[test]
+fn len_implies_get_spin() {
+    model(|| {
+        len_implies_get(Box::leak(Box::new(AbaoVec::new(leak_buf(2)))));
+    });
+}
+
+#[test]
+fn len_implies_get_seq() {
+    model(|| {
+        len_implies_get(Box::leak(Box::new(AbaoVec::new_seq(
+            leak_buf(2),
+            leak_seq(2),
+        ))));
+    });
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "serde")]
+#![deny(rust_2018_compatibility)]
+#![deny(rust_2018_idioms)]
+#![deny(warnings)]
+
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+#[test]
+fn serialize_json() {
+    let mut buf: [MaybeUninit<(u8, String)>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+    let v = AbaoVec::new(&mut buf[..]);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[]");
+
+    let expected = vec![(0, "a".to_string()), (1, "b".to_string())];
+    for t in &expected {
+        v.push(t.clone()).unwrap();
+    }
+    assert_eq!(
+        serde_json::to_string(&v).unwrap(),
+        serde_json::to_string(&expected).unwrap()
+    );
+}
@@ -0,0 +1,51 @@
+#![deny(rust_2018_compatibility)]
+#![deny(rust_2018_idioms)]
+#![deny(warnings)]
+
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::sync::{Arc, Mutex};
+
+/// Runs `f`, which has to panic on the line after the call,
+/// and returns the file and line the panic was reported at.
+///
+/// This replaces the panic hook, so it lives in its own test binary.
+fn panic_location<F: FnOnce()>(f: F) -> (String, u32) {
+    let location = Arc::new(Mutex::new(None));
+    let hook_location = Arc::clone(&location);
+    panic::set_hook(Box::new(move |info| {
+        let l = info.location().unwrap();
+        *hook_location.lock().unwrap() = Some((l.file().to_string(), l.line()));
+    }));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    let _ = panic::take_hook();
+    assert!(res.is_err());
+    let location = location.lock().unwrap().take();
+    location.unwrap()
+}
+
+#[test]
+fn index_panics_at_caller() {
+    let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    let v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+
+    let here = Location::caller();
+    let (file, line) = panic_location(|| {
+        let _ = v[1];
+    });
+    assert_eq!((file.as_str(), line), (here.file(), here.line() + 2));
+
+    let here = Location::caller();
+    let (file, line) = panic_location(|| {
+        let _ = &v[0..2];
+    });
+    assert_eq!((file.as_str(), line), (here.file(), here.line() + 2));
+
+    let here = Location::caller();
+    let (file, line) = panic_location(|| {
+        let _ = v.chunks(0);
+    });
+    assert_eq!((file.as_str(), line), (here.file(), here.line() + 2));
+}
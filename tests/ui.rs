@@ -0,0 +1,12 @@
+#![deny(rust_2018_compatibility)]
+#![deny(rust_2018_idioms)]
+#![deny(warnings)]
+
+// compile tests pinning down the borrowing and threading model of the public api
+#[test]
+#[cfg_attr(miri, ignore)]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/*-pass.rs");
+    t.compile_fail("tests/ui/*-fail.rs");
+}
@@ -0,0 +1,12 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    let mut v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+    // a shared element may not be changed through the mutable slice
+    let first = v.get(0).unwrap();
+    v.as_mut_slice()[0] = 1;
+    assert_eq!(*first, 0);
+}
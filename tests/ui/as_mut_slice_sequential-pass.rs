@@ -0,0 +1,15 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    let mut v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+    v.push(1).unwrap();
+    // shared and mutable access may take turns
+    let first = *v.get(0).unwrap();
+    v.as_mut_slice()[1] = first;
+    *v.get_mut(0).unwrap() = 2;
+    v.push(3).unwrap();
+    assert_eq!(v.as_slice(), &[2, 0, 3]);
+}
@@ -0,0 +1,13 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    let mut v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+    // there is only ever one mutable view of the elements
+    let a = v.as_mut_slice();
+    let b = v.get_mut(0).unwrap();
+    *b = 1;
+    a[0] = 2;
+}
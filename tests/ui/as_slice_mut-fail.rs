@@ -0,0 +1,14 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+    let mut v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+
+    // a slice may not be held across an exclusive borrow of the vector
+    let s = v.as_slice();
+    let m = &mut v;
+    m.push(1).unwrap();
+    assert_eq!(s, &[0]);
+}
@@ -0,0 +1,14 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 8] = unsafe { MaybeUninit::uninit().assume_init() };
+    let v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+
+    // push only needs `&self`, so a slice may be held across it
+    let s = v.as_slice();
+    v.push(1).unwrap();
+    assert_eq!(s, &[0]);
+    assert_eq!(v.as_slice(), &[0, 1]);
+}
@@ -0,0 +1,12 @@
+use abao::AbaoVec;
+use std::mem::MaybeUninit;
+
+fn main() {
+    let mut buf: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    let mut v = AbaoVec::new(&mut buf[..]);
+    v.push(0).unwrap();
+    // elements can only be changed while nobody else can see them
+    let shared = v.get(0);
+    *v.get_mut(0).unwrap() = 1;
+    assert_eq!(shared, Some(&0));
+}
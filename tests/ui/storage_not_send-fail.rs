@@ -0,0 +1,27 @@
+use abao::{AbaoVecInner, Storage};
+use std::cell::Cell;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+use std::sync::atomic::AtomicUsize;
+
+struct RcStorage(Rc<[Cell<MaybeUninit<u8>>]>);
+
+unsafe impl Storage<u8> for RcStorage {
+    fn slots(&self) -> &[Cell<MaybeUninit<u8>>] {
+        &self.0
+    }
+
+    fn seq(&self) -> Option<&[AtomicUsize]> {
+        None
+    }
+}
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+fn main() {
+    // the vector shares its storage with every thread it is sent to,
+    // so a storage which must stay on one thread makes it neither
+    assert_send::<AbaoVecInner<u8, RcStorage>>();
+    assert_sync::<AbaoVecInner<u8, RcStorage>>();
+}
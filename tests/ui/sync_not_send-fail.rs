@@ -0,0 +1,10 @@
+use abao::AbaoVec;
+use std::sync::MutexGuard;
+
+fn assert_sync<T: Sync>() {}
+
+fn main() {
+    // pushing through a shared vector moves the value to another thread,
+    // so a shared vector of `!Send` elements must not be `Sync`
+    assert_sync::<AbaoVec<'static, MutexGuard<'static, u8>>>();
+}